@@ -69,8 +69,18 @@ macro_rules! bench_unsigned {
 			(div_sum, rem_sum)
 		}
 
+		#[inline(never)]
+		fn compute_slice_division(numerators: &[$primitive_type], divisor: strength_reduce::$struct_name, quotients: &mut [$primitive_type]) {
+			strength_reduce::$struct_name::div_slice(numerators, divisor, quotients);
+		}
+
+		#[inline(never)]
+		fn compute_slice_modulo(numerators: &[$primitive_type], divisor: strength_reduce::$struct_name, remainders: &mut [$primitive_type]) {
+			strength_reduce::$struct_name::rem_slice(numerators, divisor, remainders);
+		}
+
 		fn gen_numerators() -> Vec<$primitive_type> {
-			test::black_box((0..std::$primitive_type::MAX).rev().cycle().take(REPETITIONS).collect::<Vec<$primitive_type>>())
+			test::black_box((0..$primitive_type::MAX).rev().cycle().take(REPETITIONS).collect::<Vec<$primitive_type>>())
 		}
 
 		#[bench]
@@ -129,6 +139,30 @@ macro_rules! bench_unsigned {
 			b.iter(|| { test::black_box(compute_repeated_divrem(&numerators, reduced_divisor)); });
 		}
 		
+		#[bench]
+		fn slice_division_reduced_power2(b: &mut test::Bencher) {
+			let reduced_divisor = strength_reduce::$struct_name::new(8);
+			let numerators = gen_numerators();
+			let mut quotients = vec![0; numerators.len()];
+			b.iter(|| { compute_slice_division(&numerators, reduced_divisor, &mut quotients); test::black_box(&quotients); });
+		}
+
+		#[bench]
+		fn slice_division_reduced(b: &mut test::Bencher) {
+			let reduced_divisor = strength_reduce::$struct_name::new(6);
+			let numerators = gen_numerators();
+			let mut quotients = vec![0; numerators.len()];
+			b.iter(|| { compute_slice_division(&numerators, reduced_divisor, &mut quotients); test::black_box(&quotients); });
+		}
+
+		#[bench]
+		fn slice_modulo_reduced(b: &mut test::Bencher) {
+			let reduced_divisor = strength_reduce::$struct_name::new(6);
+			let numerators = gen_numerators();
+			let mut remainders = vec![0; numerators.len()];
+			b.iter(|| { compute_slice_modulo(&numerators, reduced_divisor, &mut remainders); test::black_box(&remainders); });
+		}
+
 		#[bench]
 		fn single_division_reduced_power2(b: &mut test::Bencher) {
 			let divisors = test::black_box(vec![8; REPETITIONS]);
@@ -137,12 +171,97 @@ macro_rules! bench_unsigned {
 
 		#[bench]
 		fn single_division_reduced(b: &mut test::Bencher) {
-			let divisors = test::black_box(vec![core::$primitive_type::MAX; REPETITIONS]);
+			let divisors = test::black_box(vec![$primitive_type::MAX; REPETITIONS]);
 			b.iter(|| { test::black_box(compute_single_division(&divisors)); });
 		}
 	)
 }
 
+// StrengthReducedU16 and StrengthReducedU64 multiply the quotient back to get the remainder. These compute it directly instead, from the lower half of multiplier * numerator,
+// the way StrengthReducedU8 and StrengthReducedU32 do, so the two can be compared against repeated_modulo_reduced and slice_modulo_reduced. Only for divisors that aren't powers of two
+#[derive(Clone, Copy)]
+struct DirectRemainderU16 {
+	multiplier: u32,
+	divisor: u16,
+}
+impl DirectRemainderU16 {
+	fn new(divisor: u16) -> Self {
+		// ceil(2^32 / divisor), the same multiplier StrengthReducedU16 uses
+		Self { multiplier: u32::MAX / divisor as u32 + 1, divisor }
+	}
+
+	#[inline(always)]
+	fn rem(numerator: u16, denom: Self) -> u16 {
+		let lower_bits = denom.multiplier.wrapping_mul(numerator as u32);
+		((lower_bits as u64 * denom.divisor as u64) >> 32) as u16
+	}
+}
+
+#[derive(Clone, Copy)]
+struct DirectRemainderU64 {
+	multiplier: u128,
+	divisor: u64,
+}
+impl DirectRemainderU64 {
+	fn new(divisor: u64) -> Self {
+		// ceil(2^128 / divisor). StrengthReducedU64 doesn't store this one, so the direct remainder would need a second multiplier field
+		Self { multiplier: u128::MAX / divisor as u128 + 1, divisor }
+	}
+
+	#[inline(always)]
+	fn rem(numerator: u64, denom: Self) -> u64 {
+		let lower_bits = denom.multiplier.wrapping_mul(numerator as u128);
+
+		// upper 64 bits of the 192-bit product lower_bits * divisor
+		let product_lo = ((lower_bits as u64) as u128 * denom.divisor as u128) >> 64;
+		let product_hi = (lower_bits >> 64) * denom.divisor as u128;
+		((product_hi + product_lo) >> 64) as u64
+	}
+}
+
+macro_rules! bench_direct_remainder {
+	($direct_type:ident, $primitive_type:ident) => (
+		#[inline(never)]
+		fn compute_repeated_modulo_direct(numerators: &[$primitive_type], divisor: $direct_type) -> $primitive_type {
+			let mut sum = 0;
+			for numerator in numerators {
+				sum += $direct_type::rem(*numerator, divisor);
+			}
+			sum
+		}
+
+		#[inline(never)]
+		fn compute_slice_modulo_direct(numerators: &[$primitive_type], divisor: $direct_type, remainders: &mut [$primitive_type]) {
+			for (remainder, numerator) in remainders.iter_mut().zip(numerators) {
+				*remainder = $direct_type::rem(*numerator, divisor);
+			}
+		}
+
+		fn gen_direct_divisor() -> $direct_type {
+			let divisor = $direct_type::new(6);
+			for numerator in gen_numerators() {
+				assert_eq!($direct_type::rem(numerator, divisor), numerator % 6);
+			}
+			divisor
+		}
+
+		#[bench]
+		fn repeated_modulo_direct(b: &mut test::Bencher) {
+			let divisor = gen_direct_divisor();
+			let numerators = gen_numerators();
+			b.iter(|| { test::black_box(compute_repeated_modulo_direct(&numerators, divisor)); });
+		}
+
+		#[bench]
+		fn slice_modulo_direct(b: &mut test::Bencher) {
+			let divisor = gen_direct_divisor();
+			let numerators = gen_numerators();
+			let mut remainders = vec![0; numerators.len()];
+			b.iter(|| { compute_slice_modulo_direct(&numerators, divisor, &mut remainders); test::black_box(&remainders); });
+		}
+	)
+}
+
 mod bench_u08 {
 	use super::*;
 	bench_unsigned!(StrengthReducedU8, u8);
@@ -150,6 +269,7 @@ mod bench_u08 {
 mod bench_u16 {
 	use super::*;
 	bench_unsigned!(StrengthReducedU16, u16);
+	bench_direct_remainder!(DirectRemainderU16, u16);
 }
 mod bench_u32 {
 	use super::*;
@@ -158,6 +278,7 @@ mod bench_u32 {
 mod bench_u64 {
 	use super::*;
 	bench_unsigned!(StrengthReducedU64, u64);
+	bench_direct_remainder!(DirectRemainderU64, u64);
 
 	// generates random divisors with values in the range [1<<bit_min, 1<<bit_max)
 	fn generate_random_divisors(bit_min: u32, bit_max: u32, count: usize) -> Vec<u64> {
@@ -165,7 +286,7 @@ mod bench_u64 {
 		assert!(bit_max <= 64);
 
 		let min_value = 1u64 << bit_min;
-		let max_value = 1u64.checked_shl(bit_max).map_or(core::u64::MAX, |v| v - 1);
+		let max_value = 1u64.checked_shl(bit_max).map_or(u64::MAX, |v| v - 1);
 
 		let mut gen = StdRng::seed_from_u64(5673573);
 		let dist = Uniform::new_inclusive(min_value, max_value);
@@ -195,7 +316,7 @@ mod bench_u128 {
 		assert!(bit_max <= 128);
 
 		let min_value = 1u128 << bit_min;
-		let max_value = 1u128.checked_shl(bit_max).map_or(core::u128::MAX, |v| v - 1);
+		let max_value = 1u128.checked_shl(bit_max).map_or(u128::MAX, |v| v - 1);
 
 		let mut gen = StdRng::seed_from_u64(5673573);
 		let dist = Uniform::new_inclusive(min_value, max_value);
@@ -224,4 +345,230 @@ mod bench_u128 {
 		let divisors = test::black_box(generate_random_divisors(96, 128, REPETITIONS));
 		b.iter(|| { test::black_box(compute_single_division(&divisors)); });
 	}
-}
\ No newline at end of file
+
+	// numerators that fit in 64 bits take a shortcut, so we measure those separately, with small and large divisors, and mixed with full-size numerators
+	fn generate_mixed_numerators(count: usize) -> Vec<u128> {
+		let mut gen = StdRng::seed_from_u64(5673573);
+		let small_dist = Uniform::new_inclusive(0, u64::MAX as u128);
+		let large_dist = Uniform::new_inclusive(u64::MAX as u128 + 1, u128::MAX);
+		let coin_dist = Uniform::new(0, 2);
+
+		(0..count).map(|_| if coin_dist.sample(&mut gen) == 0 { small_dist.sample(&mut gen) } else { large_dist.sample(&mut gen) }).collect()
+	}
+
+	#[bench]
+	fn division_standard_064bit_numerators(b: &mut test::Bencher) {
+		let numerators = test::black_box((0..u64::MAX as u128).rev().take(REPETITIONS).collect::<Vec<u128>>());
+		b.iter(|| { test::black_box(compute_repeated_division_primitive(&numerators, 6)); });
+	}
+	#[bench]
+	fn repeated_division_reduced_064bit_numerators(b: &mut test::Bencher) {
+		let reduced_divisor = strength_reduce::StrengthReducedU128::new(6);
+		let numerators = test::black_box((0..u64::MAX as u128).rev().take(REPETITIONS).collect::<Vec<u128>>());
+		b.iter(|| { test::black_box(compute_repeated_division(&numerators, reduced_divisor)); });
+	}
+	#[bench]
+	fn division_standard_064bit_numerators_128bit_divisor(b: &mut test::Bencher) {
+		let numerators = test::black_box((0..u64::MAX as u128).rev().take(REPETITIONS).collect::<Vec<u128>>());
+		b.iter(|| { test::black_box(compute_repeated_division_primitive(&numerators, u128::MAX / 6)); });
+	}
+	#[bench]
+	fn repeated_division_reduced_064bit_numerators_128bit_divisor(b: &mut test::Bencher) {
+		let reduced_divisor = strength_reduce::StrengthReducedU128::new(u128::MAX / 6);
+		let numerators = test::black_box((0..u64::MAX as u128).rev().take(REPETITIONS).collect::<Vec<u128>>());
+		b.iter(|| { test::black_box(compute_repeated_division(&numerators, reduced_divisor)); });
+	}
+	#[bench]
+	fn division_standard_mixed_numerators(b: &mut test::Bencher) {
+		let numerators = test::black_box(generate_mixed_numerators(REPETITIONS));
+		b.iter(|| { test::black_box(compute_repeated_division_primitive(&numerators, 6)); });
+	}
+	#[bench]
+	fn repeated_division_reduced_mixed_numerators(b: &mut test::Bencher) {
+		let reduced_divisor = strength_reduce::StrengthReducedU128::new(6);
+		let numerators = test::black_box(generate_mixed_numerators(REPETITIONS));
+		b.iter(|| { test::black_box(compute_repeated_division(&numerators, reduced_divisor)); });
+	}
+	#[bench]
+	fn repeated_modulo_reduced_mixed_numerators(b: &mut test::Bencher) {
+		let reduced_divisor = strength_reduce::StrengthReducedU128::new(6);
+		let numerators = test::black_box(generate_mixed_numerators(REPETITIONS));
+		b.iter(|| { test::black_box(compute_repeated_modulo(&numerators, reduced_divisor)); });
+	}
+}
+
+// Each numerator gets its own divisor, drawn from a random mix of powers of two and other values, which is the case the branch-free types are for:
+// the regular types branch on whether the divisor is a power of two, and that branch can't be predicted here
+macro_rules! bench_varying_divisors {
+	($struct_name:ident, $branchfree_struct_name:ident, $primitive_type:ident) => (
+		fn generate_mixed_divisors(count: usize) -> Vec<$primitive_type> {
+			let mut gen = StdRng::seed_from_u64(5673573);
+			let exponent_dist = Uniform::new(0, $primitive_type::MAX.count_ones());
+			let value_dist = Uniform::new_inclusive(1, $primitive_type::MAX);
+			let coin_dist = Uniform::new(0, 2);
+
+			(0..count).map(|_| if coin_dist.sample(&mut gen) == 0 { 1 << exponent_dist.sample(&mut gen) } else { value_dist.sample(&mut gen) }).collect()
+		}
+
+		#[inline(never)]
+		fn compute_varying_division_primitive(numerators: &[$primitive_type], divisors: &[$primitive_type]) -> $primitive_type {
+			let mut sum: $primitive_type = 0;
+			for (numerator, divisor) in numerators.iter().zip(divisors) {
+				sum = sum.wrapping_add(*numerator / *divisor);
+			}
+			sum
+		}
+
+		#[inline(never)]
+		fn compute_varying_division_reduced(numerators: &[$primitive_type], divisors: &[strength_reduce::$struct_name]) -> $primitive_type {
+			let mut sum: $primitive_type = 0;
+			for (numerator, divisor) in numerators.iter().zip(divisors) {
+				sum = sum.wrapping_add(*numerator / *divisor);
+			}
+			sum
+		}
+
+		#[inline(never)]
+		fn compute_varying_division_branchfree(numerators: &[$primitive_type], divisors: &[strength_reduce::$branchfree_struct_name]) -> $primitive_type {
+			let mut sum: $primitive_type = 0;
+			for (numerator, divisor) in numerators.iter().zip(divisors) {
+				sum = sum.wrapping_add(*numerator / *divisor);
+			}
+			sum
+		}
+
+		#[bench]
+		fn varying_division_standard(b: &mut test::Bencher) {
+			let numerators: Vec<$primitive_type> = (0..REPETITIONS as $primitive_type).map(|i| i.wrapping_mul(0x9E3779B97F4A7C15u64 as $primitive_type)).collect();
+			let divisors = generate_mixed_divisors(REPETITIONS);
+			b.iter(|| { test::black_box(compute_varying_division_primitive(&numerators, &divisors)); });
+		}
+
+		#[bench]
+		fn varying_division_reduced(b: &mut test::Bencher) {
+			let numerators: Vec<$primitive_type> = (0..REPETITIONS as $primitive_type).map(|i| i.wrapping_mul(0x9E3779B97F4A7C15u64 as $primitive_type)).collect();
+			let divisors: Vec<_> = generate_mixed_divisors(REPETITIONS).into_iter().map(strength_reduce::$struct_name::new).collect();
+			b.iter(|| { test::black_box(compute_varying_division_reduced(&numerators, &divisors)); });
+		}
+
+		#[bench]
+		fn varying_division_branchfree(b: &mut test::Bencher) {
+			let numerators: Vec<$primitive_type> = (0..REPETITIONS as $primitive_type).map(|i| i.wrapping_mul(0x9E3779B97F4A7C15u64 as $primitive_type)).collect();
+			let divisors: Vec<_> = generate_mixed_divisors(REPETITIONS).into_iter().map(strength_reduce::$branchfree_struct_name::new).collect();
+			b.iter(|| { test::black_box(compute_varying_division_branchfree(&numerators, &divisors)); });
+		}
+	)
+}
+
+mod bench_branchfree_u32 {
+	use super::*;
+	bench_varying_divisors!(StrengthReducedU32, StrengthReducedBranchfreeU32, u32);
+}
+mod bench_branchfree_u64 {
+	use super::*;
+	bench_varying_divisors!(StrengthReducedU64, StrengthReducedBranchfreeU64, u64);
+}
+
+macro_rules! bench_modular {
+	($struct_name:ident, $montgomery_name:ident, $primitive_type:ident, $wide_type:ident) => (
+		// odd, so that the Montgomery benchmarks can use it too
+		const MODULUS: $primitive_type = $primitive_type::MAX - 58;
+
+		#[inline(never)]
+		fn compute_repeated_mul_mod_primitive(values: &[$primitive_type], modulus: $primitive_type) -> $primitive_type {
+			let mut product = 1;
+			for value in values {
+				product = ((product as $wide_type * *value as $wide_type) % modulus as $wide_type) as $primitive_type;
+			}
+			product
+		}
+
+		#[inline(never)]
+		fn compute_repeated_mul_mod(values: &[$primitive_type], modular: strength_reduce::$struct_name) -> $primitive_type {
+			let mut product = 1;
+			for value in values {
+				product = modular.mul_mod(product, *value);
+			}
+			product
+		}
+
+		#[inline(never)]
+		fn compute_repeated_mul_montgomery(values: &[$primitive_type], montgomery: strength_reduce::$montgomery_name) -> $primitive_type {
+			let mut product = montgomery.one();
+			for value in values {
+				product = montgomery.mul(product, *value);
+			}
+			montgomery.from_montgomery(product)
+		}
+
+		#[inline(never)]
+		fn compute_pow_mod_primitive(mut base: $primitive_type, mut exponent: $primitive_type, modulus: $primitive_type) -> $primitive_type {
+			let mut result = 1;
+			while exponent > 0 {
+				if exponent & 1 == 1 {
+					result = ((result as $wide_type * base as $wide_type) % modulus as $wide_type) as $primitive_type;
+				}
+				base = ((base as $wide_type * base as $wide_type) % modulus as $wide_type) as $primitive_type;
+				exponent >>= 1;
+			}
+			result
+		}
+
+		fn gen_values() -> Vec<$primitive_type> {
+			let mut gen = StdRng::seed_from_u64(5673573);
+			let dist = Uniform::new_inclusive(1, MODULUS - 1);
+			test::black_box(dist.sample_iter(&mut gen).take(REPETITIONS).collect::<Vec<$primitive_type>>())
+		}
+
+		#[bench]
+		fn mul_mod_standard(b: &mut test::Bencher) {
+			let values = gen_values();
+			let modulus = test::black_box(MODULUS);
+			b.iter(|| { test::black_box(compute_repeated_mul_mod_primitive(&values, modulus)); });
+		}
+
+		#[bench]
+		fn repeated_mul_mod_reduced(b: &mut test::Bencher) {
+			let values = gen_values();
+			let modular = strength_reduce::$struct_name::new(MODULUS);
+			b.iter(|| { test::black_box(compute_repeated_mul_mod(&values, modular)); });
+		}
+
+		#[bench]
+		fn pow_mod_standard(b: &mut test::Bencher) {
+			let bases = gen_values();
+			let modulus = test::black_box(MODULUS);
+			b.iter(|| { test::black_box(bases.iter().map(|base| compute_pow_mod_primitive(*base, modulus - 1, modulus)).fold(0, |sum: $primitive_type, value| sum.wrapping_add(value))); });
+		}
+
+		#[bench]
+		fn repeated_pow_mod_reduced(b: &mut test::Bencher) {
+			let bases = gen_values();
+			let modular = strength_reduce::$struct_name::new(MODULUS);
+			b.iter(|| { test::black_box(bases.iter().map(|base| modular.pow_mod(*base, MODULUS - 1)).fold(0, |sum: $primitive_type, value| sum.wrapping_add(value))); });
+		}
+
+		#[bench]
+		fn repeated_mul_montgomery(b: &mut test::Bencher) {
+			let montgomery = strength_reduce::$montgomery_name::new(MODULUS);
+			let values: Vec<$primitive_type> = gen_values().iter().map(|value| montgomery.to_montgomery(*value)).collect();
+			b.iter(|| { test::black_box(compute_repeated_mul_montgomery(&values, montgomery)); });
+		}
+
+		#[bench]
+		fn repeated_pow_montgomery(b: &mut test::Bencher) {
+			let bases = gen_values();
+			let montgomery = strength_reduce::$montgomery_name::new(MODULUS);
+			b.iter(|| { test::black_box(bases.iter().map(|base| montgomery.from_montgomery(montgomery.pow(montgomery.to_montgomery(*base), MODULUS - 1))).fold(0, |sum: $primitive_type, value| sum.wrapping_add(value))); });
+		}
+	)
+}
+
+mod bench_modular_u32 {
+	use super::*;
+	bench_modular!(ModularU32, Montgomery32, u32, u64);
+}
+mod bench_modular_u64 {
+	use super::*;
+	bench_modular!(ModularU64, Montgomery64, u64, u128);
+}
@@ -0,0 +1,94 @@
+// Division of num-bigint's BigUint by a word-sized reduced divisor.
+// These run the same limb-by-limb long division as div_rem_limbs, reading the limbs straight out of the BigUint, so the quotient of each limb reuses the divisor's multiplier.
+
+use alloc::vec::Vec;
+use core::ops::{Div, Rem};
+
+use num_bigint::BigUint;
+
+use ::{StrengthReducedU32, StrengthReducedU64};
+
+macro_rules! impl_biguint_division {
+    ($struct_name:ident, $primitive_type:ident, $iter_digits:ident) => (
+        impl $struct_name {
+            /// Simultaneous truncated division and modulus of an arbitrary-precision numerator.
+            /// Returns `(quotient, remainder)`. The remainder is always smaller than the divisor, so it's returned as a primitive integer.
+            #[inline]
+            pub fn div_rem_biguint(numerator: &BigUint, denom: Self) -> (BigUint, $primitive_type) {
+                let mut quotient = Vec::new();
+                let remainder = Self::div_rem_biguint_digits(numerator, denom, &mut quotient);
+                (BigUint::new(quotient), remainder)
+            }
+
+            /// Modulus of an arbitrary-precision numerator. The remainder is always smaller than the divisor, so unlike `%`, this returns it as a primitive integer.
+            #[inline]
+            pub fn rem_biguint(numerator: &BigUint, denom: Self) -> $primitive_type {
+                numerator.$iter_digits().rev().fold(0, |remainder, numerator_limb| Self::div_rem_limb(remainder, numerator_limb, denom).1)
+            }
+
+            // The same long division as div_rem_limbs, reading the limbs straight out of the BigUint instead of copying them out first.
+            // The quotient is stored as little-endian u32 digits, which is what a BigUint is built from
+            fn div_rem_biguint_digits(numerator: &BigUint, denom: Self, quotient: &mut Vec<u32>) -> $primitive_type {
+                const DIGITS_PER_LIMB: usize = $primitive_type::BITS as usize / 32;
+
+                let limbs = numerator.$iter_digits();
+                quotient.resize(limbs.len() * DIGITS_PER_LIMB, 0);
+
+                let mut remainder = 0;
+                for (quotient_digits, numerator_limb) in quotient.chunks_exact_mut(DIGITS_PER_LIMB).rev().zip(limbs.rev()) {
+                    let (limb_quotient, limb_remainder) = Self::div_rem_limb(remainder, numerator_limb, denom);
+                    for (index, digit) in quotient_digits.iter_mut().enumerate() {
+                        *digit = (limb_quotient >> (32 * index)) as u32;
+                    }
+                    remainder = limb_remainder;
+                }
+                remainder
+            }
+        }
+
+        // BigUint doesn't hand out its digits, so the quotient still goes through a scratch vector, but it's written back into the numerator's own buffer instead of a new one
+        impl Div<$struct_name> for BigUint {
+            type Output = BigUint;
+
+            #[inline]
+            fn div(mut self, rhs: $struct_name) -> Self::Output {
+                let mut quotient = Vec::new();
+                $struct_name::div_rem_biguint_digits(&self, rhs, &mut quotient);
+                self.assign_from_slice(&quotient);
+                self
+            }
+        }
+
+        impl<'a> Div<$struct_name> for &'a BigUint {
+            type Output = BigUint;
+
+            #[inline]
+            fn div(self, rhs: $struct_name) -> Self::Output {
+                $struct_name::div_rem_biguint(self, rhs).0
+            }
+        }
+
+        // Like `BigUint % u64`, the remainder is returned as a BigUint, so that the divisor can stand in for a primitive one in code bounded on `Rem<Output = BigUint>`.
+        // rem_biguint returns it as a primitive integer instead
+        impl Rem<$struct_name> for BigUint {
+            type Output = BigUint;
+
+            #[inline]
+            fn rem(self, rhs: $struct_name) -> Self::Output {
+                &self % rhs
+            }
+        }
+
+        impl<'a> Rem<$struct_name> for &'a BigUint {
+            type Output = BigUint;
+
+            #[inline]
+            fn rem(self, rhs: $struct_name) -> Self::Output {
+                BigUint::from($struct_name::rem_biguint(self, rhs))
+            }
+        }
+    )
+}
+
+impl_biguint_division!(StrengthReducedU32, u32, iter_u32_digits);
+impl_biguint_division!(StrengthReducedU64, u64, iter_u64_digits);
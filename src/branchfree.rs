@@ -0,0 +1,464 @@
+// Branch-free variants of the unsigned divisors. The regular types test for a power of two on every division, which is free when the divisor doesn't change,
+// but mispredicts constantly when each division uses a different divisor. These types fold powers of two into the same arithmetic as every other divisor,
+// so dividing is one fixed sequence of instructions, at the cost of a little extra work per division.
+//
+// That also makes them usable from constant-time code. The numerator path is written against the Word trait below rather than directly on u64,
+// and Word has no comparisons and no way to get at the value inside, so the numerator path can't branch on the numerator or the divisor even by accident.
+// The unit tests run it on a word type that fingerprints every operation, to check that the same operations run for every numerator and divisor.
+
+use core::ops::{Div, Rem};
+
+use long_division;
+
+// Every operation the numerator path is allowed to perform. All of them wrap, and none of them take a time that depends on their operands on any mainstream CPU
+pub(crate) trait Word: Copy {
+    fn mul_hi(self, other: Self) -> Self;
+    fn mul_lo(self, other: Self) -> Self;
+    fn add(self, other: Self) -> Self;
+    fn sub(self, other: Self) -> Self;
+    fn and(self, other: Self) -> Self;
+    fn shr(self, shift: u32) -> Self;
+}
+
+impl Word for u64 {
+    #[inline(always)]
+    fn mul_hi(self, other: Self) -> Self {
+        ((self as u128 * other as u128) >> 64) as u64
+    }
+    #[inline(always)]
+    fn mul_lo(self, other: Self) -> Self {
+        self.wrapping_mul(other)
+    }
+    #[inline(always)]
+    fn add(self, other: Self) -> Self {
+        self.wrapping_add(other)
+    }
+    #[inline(always)]
+    fn sub(self, other: Self) -> Self {
+        self.wrapping_sub(other)
+    }
+    #[inline(always)]
+    fn and(self, other: Self) -> Self {
+        self & other
+    }
+    #[inline(always)]
+    fn shr(self, shift: u32) -> Self {
+        self >> shift
+    }
+}
+
+// The upper half of numerator * (multiplier + 1), for a numerator that fits in 32 bits. Adding the numerator to the lower partial product can't overflow, because the numerator only has 32 bits
+#[inline(always)]
+fn divide_u32<W: Word>(numerator: W, multiplier: W, lower_mask: W) -> W {
+    let multiplied_hi = numerator.mul_lo(multiplier.shr(32));
+    let multiplied_lo = numerator.mul_lo(multiplier.and(lower_mask)).add(numerator).shr(32);
+
+    multiplied_hi.add(multiplied_lo).shr(32)
+}
+
+// (numerator + product) >> (shift + 1), without overflowing. The product is never larger than the numerator
+#[inline(always)]
+fn divide_u64<W: Word>(numerator: W, multiplier: W, numerator_mask: W, shift: u32) -> W {
+    let product = numerator.mul_hi(multiplier).add(numerator.and(numerator_mask));
+    numerator.sub(product).shr(1).add(product).shr(shift)
+}
+
+#[inline(always)]
+fn remainder<W: Word>(numerator: W, quotient: W, divisor: W) -> W {
+    numerator.sub(quotient.mul_lo(divisor))
+}
+
+// The divisibility tests, exact division and slice functions, built on the fixed sequence above. The regular divisor types test divisibility with a modular inverse,
+// which would mean storing two more fields here, so these just check the remainder instead. That makes them no faster than `%`, but it keeps them branch-free,
+// and the slice loops run the same instructions for every element and every divisor, without first testing for a power of two like the regular types do
+macro_rules! impl_branchfree_ops {
+    ($struct_name:ident, $primitive_type:ident) => (
+        impl $struct_name {
+            /// Returns true if `numerator` is a multiple of the divisor, ie if `numerator % divisor == 0`.
+            ///
+            /// Unlike the regular divisor types, this computes the remainder, so it's no faster than `%`.
+            #[inline]
+            pub fn is_multiple_of(numerator: $primitive_type, denom: Self) -> bool {
+                numerator % denom == 0
+            }
+
+            /// Returns true if this divisor divides `numerator` evenly. Equivalent to `is_multiple_of(numerator, divisor)`
+            #[inline]
+            pub fn divides(&self, numerator: $primitive_type) -> bool {
+                Self::is_multiple_of(numerator, *self)
+            }
+
+            /// Divides `numerator` by the divisor, assuming that `numerator` is an exact multiple of the divisor.
+            ///
+            /// Unlike the regular divisor types, this is the same division as `/`. It's provided so that code can switch between the divisor types without changes.
+            ///
+            /// # Panics:
+            ///
+            /// In debug builds, panics if `numerator` isn't a multiple of the divisor
+            #[inline]
+            pub fn divide_exact(numerator: $primitive_type, denom: Self) -> $primitive_type {
+                let (quotient, remainder) = Self::div_rem(numerator, denom);
+                debug_assert!(remainder == 0, "divide_exact called with a numerator that isn't a multiple of the divisor");
+                quotient
+            }
+
+            /// Divides `numerator` by the divisor if `numerator` is an exact multiple of the divisor. Returns `None` otherwise.
+            #[inline]
+            pub fn checked_divide_exact(numerator: $primitive_type, denom: Self) -> Option<$primitive_type> {
+                let (quotient, remainder) = Self::div_rem(numerator, denom);
+                if remainder == 0 { Some(quotient) } else { None }
+            }
+
+            /// Divides every element of `numerators` by the divisor, storing the results in `quotients`.
+            ///
+            /// # Panics:
+            ///
+            /// Panics if `numerators` and `quotients` have different lengths
+            #[inline]
+            pub fn div_slice(numerators: &[$primitive_type], denom: Self, quotients: &mut [$primitive_type]) {
+                assert_eq!(numerators.len(), quotients.len(), "numerators and quotients must have the same length");
+                for (quotient, &numerator) in quotients.iter_mut().zip(numerators) {
+                    *quotient = numerator / denom;
+                }
+            }
+
+            /// Computes the remainder of every element of `numerators` divided by the divisor, storing the results in `remainders`.
+            ///
+            /// # Panics:
+            ///
+            /// Panics if `numerators` and `remainders` have different lengths
+            #[inline]
+            pub fn rem_slice(numerators: &[$primitive_type], denom: Self, remainders: &mut [$primitive_type]) {
+                assert_eq!(numerators.len(), remainders.len(), "numerators and remainders must have the same length");
+                for (remainder, &numerator) in remainders.iter_mut().zip(numerators) {
+                    *remainder = numerator % denom;
+                }
+            }
+
+            /// Simultaneous truncated division and modulus of every element of `numerators`, storing the results in `quotients` and `remainders`.
+            ///
+            /// # Panics:
+            ///
+            /// Panics if `numerators`, `quotients` and `remainders` don't all have the same length
+            #[inline]
+            pub fn div_rem_slice(numerators: &[$primitive_type], denom: Self, quotients: &mut [$primitive_type], remainders: &mut [$primitive_type]) {
+                assert_eq!(numerators.len(), quotients.len(), "numerators and quotients must have the same length");
+                assert_eq!(numerators.len(), remainders.len(), "numerators and remainders must have the same length");
+                for ((quotient, remainder), &numerator) in quotients.iter_mut().zip(remainders.iter_mut()).zip(numerators) {
+                    let (divided, remaining) = Self::div_rem(numerator, denom);
+                    *quotient = divided;
+                    *remainder = remaining;
+                }
+            }
+
+            /// Divides every element of `values` by the divisor, in place.
+            #[inline]
+            pub fn div_slice_in_place(values: &mut [$primitive_type], denom: Self) {
+                for value in values.iter_mut() {
+                    *value /= denom;
+                }
+            }
+
+            /// Replaces every element of `values` with its remainder when divided by the divisor, in place.
+            #[inline]
+            pub fn rem_slice_in_place(values: &mut [$primitive_type], denom: Self) {
+                for value in values.iter_mut() {
+                    *value %= denom;
+                }
+            }
+        }
+    )
+}
+
+/// Implements unsigned division and modulo via multiplication and shifts, without branching on the divisor.
+///
+/// Unlike `StrengthReducedU32`, dividing by this type doesn't test whether the divisor is a power of two, so every division runs the same instructions.
+/// Prefer it when the divisors change unpredictably from one division to the next, e.g. when each row of a table has its own divisor.
+/// When the same divisor is used over and over, `StrengthReducedU32` is faster.
+///
+/// It has the same API as `StrengthReducedU32`, except for the wide and multi-limb division functions, the `BigUint` operators and the SIMD vector operators,
+/// which are about dividing many numerators by one divisor, where `StrengthReducedU32` is the better choice anyway.
+/// `is_multiple_of` and `divide_exact` are only there for compatibility: they compute the remainder or quotient the same way `%` and `/` do, so they aren't faster.
+///
+/// # Constant time:
+///
+/// `/`, `%` and `div_rem` run exactly the same sequence of multiplications, additions, subtractions, bitwise ANDs and shifts for every numerator and every divisor,
+/// with no branches, early exits or table lookups, so they can be used on secret numerators. This is tested, but it's only a property of the source code:
+/// it relies on the compiler not turning the arithmetic back into branches, and on the target multiplying in constant time.
+///
+/// The slice functions run that same sequence on every element. The rest of the API isn't covered. In particular, `new()` divides by the divisor,
+/// which isn't constant-time on most CPUs, and the rounding functions like `div_ceil`, as well as `is_multiple_of` and `checked_divide_exact`, compare the remainder against zero.
+#[derive(Clone, Copy, Debug)]
+pub struct StrengthReducedBranchfreeU32 {
+    multiplier: u64,
+    divisor: u32,
+}
+impl StrengthReducedBranchfreeU32 {
+    /// Creates a new divisor instance.
+    ///
+    /// If possible, avoid calling new() from an inner loop: The intended usage is to create an instance of this struct outside the loop, and use it for division and remainders inside the loop.
+    ///
+    /// # Panics:
+    ///
+    /// Panics if `divisor` is 0
+    #[inline]
+    pub const fn new(divisor: u32) -> Self {
+        assert!(divisor > 0);
+
+        // The quotient is the upper half of numerator * ceil(2^64 / divisor), like StrengthReducedU32. That's exact for powers of two too,
+        // but it doesn't fit in a u64 when the divisor is 1, so we store it minus one and add the numerator back in when dividing.
+        // ceil(2^64 / divisor) - 1 is floor((2^64 - 1) / divisor) for every divisor, powers of two included
+        Self{ multiplier: u64::MAX / divisor as u64, divisor }
+    }
+
+    // Same check as StrengthReducedU8::from_multiplier, except that the stored multiplier is floor((2^64 - 1) / divisor): multiplier * divisor doesn't overflow, but (multiplier + 1) * divisor does
+    #[cfg(feature = "serde")]
+    pub(crate) fn from_multiplier(divisor: u32, multiplier: u64) -> Option<Self> {
+        if divisor == 0 || multiplier.checked_mul(divisor as u64).is_none() || multiplier.checked_add(1).and_then(|next| next.checked_mul(divisor as u64)).is_some() {
+            None
+        } else {
+            Some(Self{ multiplier, divisor })
+        }
+    }
+
+    #[cfg(feature = "serde")]
+    pub(crate) fn multiplier(&self) -> u64 {
+        self.multiplier
+    }
+
+    /// Simultaneous truncated integer division and modulus.
+    /// Returns `(quotient, remainder)`.
+    #[inline]
+    pub fn div_rem(numerator: u32, denom: Self) -> (u32, u32) {
+        let quotient = numerator / denom;
+        (quotient, remainder(numerator as u64, quotient as u64, denom.divisor as u64) as u32)
+    }
+
+    /// Retrieve the value used to create this struct
+    #[inline]
+    pub fn get(&self) -> u32 {
+        self.divisor
+    }
+}
+
+impl Div<StrengthReducedBranchfreeU32> for u32 {
+    type Output = u32;
+
+    #[inline]
+    fn div(self, rhs: StrengthReducedBranchfreeU32) -> Self::Output {
+        divide_u32(self as u64, rhs.multiplier, u32::MAX as u64) as u32
+    }
+}
+
+impl Rem<StrengthReducedBranchfreeU32> for u32 {
+    type Output = u32;
+
+    #[inline]
+    fn rem(self, rhs: StrengthReducedBranchfreeU32) -> Self::Output {
+        StrengthReducedBranchfreeU32::div_rem(self, rhs).1
+    }
+}
+
+/// Implements unsigned division and modulo via multiplication and shifts, without branching on the divisor.
+///
+/// Unlike `StrengthReducedU64`, dividing by this type doesn't test whether the divisor is a power of two, or which of two multiplier forms it uses,
+/// so every division runs the same instructions. Prefer it when the divisors change unpredictably from one division to the next,
+/// e.g. when each row of a table has its own divisor. When the same divisor is used over and over, `StrengthReducedU64` is faster.
+///
+/// Like `StrengthReducedBranchfreeU32`, it has the same API as its regular counterpart, minus the wide, multi-limb, `BigUint` and SIMD vector division.
+///
+/// # Constant time:
+///
+/// `/`, `%` and `div_rem` have the same constant-time properties as they do for `StrengthReducedBranchfreeU32`:
+/// the same instructions run for every numerator and every divisor, as long as the target has a constant-time 64x64 to 128-bit multiply.
+/// The slice functions are covered too. `new()`, the rounding functions and the divisibility tests aren't.
+#[derive(Clone, Copy, Debug)]
+pub struct StrengthReducedBranchfreeU64 {
+    multiplier: u64,
+    divisor: u64,
+    shift: u32,
+    numerator_mask: u64,
+}
+impl StrengthReducedBranchfreeU64 {
+    /// Creates a new divisor instance.
+    ///
+    /// If possible, avoid calling new() from an inner loop: The intended usage is to create an instance of this struct outside the loop, and use it for division and remainders inside the loop.
+    ///
+    /// # Panics:
+    ///
+    /// Panics if `divisor` is 0
+    #[inline]
+    pub const fn new(divisor: u64) -> Self {
+        assert!(divisor > 0);
+
+        // This is libdivide's branch-free scheme. StrengthReducedU64 only uses the 65-bit multiplier ceil(2^(65 + shift) / divisor) when the 64-bit one is too imprecise,
+        // but that means branching on which one the divisor uses, so here every divisor uses the 65-bit form: the quotient is (numerator + mulhi(numerator, multiplier)) >> (shift + 1).
+        // For a power of two 2^k, a multiplier of 0 and a shift of k - 1 give numerator >> k with the same instructions.
+        // That leaves the divisor 1, which would need a shift of -1. Instead, numerator_mask adds the whole numerator to the product, which makes the quotient the numerator itself
+        if divisor == 1 {
+            Self{ multiplier: 0, divisor, shift: 0, numerator_mask: u64::MAX }
+        } else if divisor.is_power_of_two() {
+            Self{ multiplier: 0, divisor, shift: divisor.trailing_zeros() - 1, numerator_mask: 0 }
+        } else {
+            // Same math as StrengthReducedU64::new(): floor(2^(65 + shift) / divisor) is twice floor(2^(64 + shift) / divisor), plus one if twice the remainder reaches the divisor
+            let shift = 63 - divisor.leading_zeros();
+            let quotient = long_division::divide_power_of_two_by_64(divisor);
+            let remainder = quotient.wrapping_mul(divisor).wrapping_neg();
+            let doubled_quotient = quotient.wrapping_add(quotient) + (remainder >= divisor - remainder) as u64;
+            Self{ multiplier: doubled_quotient.wrapping_add(1), divisor, shift, numerator_mask: 0 }
+        }
+    }
+
+    // The multiplier is the lower 64 bits of ceil(2^(65 + shift) / divisor), or 0 for powers of two. Like StrengthReducedU64::from_multiplier, this checks it without dividing:
+    // 2^(65 + shift) is a multiple of 2^64, so for the right multiplier, the full product (2^64 + multiplier) * divisor exceeds it by less than the divisor, in its lower half
+    #[cfg(feature = "serde")]
+    pub(crate) fn from_multiplier(divisor: u64, multiplier: u64) -> Option<Self> {
+        if divisor == 0 {
+            None
+        } else if divisor.is_power_of_two() {
+            if multiplier == 0 { Some(Self::new(divisor)) } else { None }
+        } else {
+            let shift = 63 - divisor.leading_zeros();
+            let product = multiplier as u128 * divisor as u128;
+            let product_hi = (product >> 64) + divisor as u128;
+            if (product as u64) < divisor && product_hi == 1 << (shift + 1) {
+                Some(Self{ multiplier, divisor, shift, numerator_mask: 0 })
+            } else {
+                None
+            }
+        }
+    }
+
+    #[cfg(feature = "serde")]
+    pub(crate) fn multiplier(&self) -> u64 {
+        self.multiplier
+    }
+
+    /// Simultaneous truncated integer division and modulus.
+    /// Returns `(quotient, remainder)`.
+    #[inline]
+    pub fn div_rem(numerator: u64, denom: Self) -> (u64, u64) {
+        let quotient = numerator / denom;
+        (quotient, remainder(numerator, quotient, denom.divisor))
+    }
+
+    /// Retrieve the value used to create this struct
+    #[inline]
+    pub fn get(&self) -> u64 {
+        self.divisor
+    }
+}
+
+impl Div<StrengthReducedBranchfreeU64> for u64 {
+    type Output = u64;
+
+    #[inline]
+    fn div(self, rhs: StrengthReducedBranchfreeU64) -> Self::Output {
+        divide_u64(self, rhs.multiplier, rhs.numerator_mask, rhs.shift)
+    }
+}
+
+impl Rem<StrengthReducedBranchfreeU64> for u64 {
+    type Output = u64;
+
+    #[inline]
+    fn rem(self, rhs: StrengthReducedBranchfreeU64) -> Self::Output {
+        StrengthReducedBranchfreeU64::div_rem(self, rhs).1
+    }
+}
+
+impl_branchfree_ops!(StrengthReducedBranchfreeU32, u32);
+impl_branchfree_ops!(StrengthReducedBranchfreeU64, u64);
+
+#[cfg(test)]
+mod unit_tests {
+    use super::*;
+
+    // A word that carries a fingerprint of every operation that led to it, alongside its value. Two results have the same fingerprint
+    // only if they were computed by the same operations applied in the same order to the same inputs, regardless of what the values were
+    #[derive(Clone, Copy, Debug)]
+    struct Traced {
+        value: u64,
+        trace: u64,
+    }
+
+    impl Traced {
+        fn input(value: u64, input_index: u64) -> Self {
+            Traced { value, trace: input_index }
+        }
+
+        fn combine(self, other_trace: u64, operation: u64, value: u64) -> Self {
+            let trace = (self.trace.rotate_left(5) ^ other_trace.rotate_left(29) ^ operation).wrapping_mul(0x9E3779B97F4A7C15);
+            Traced { value, trace }
+        }
+    }
+
+    impl Word for Traced {
+        fn mul_hi(self, other: Self) -> Self {
+            self.combine(other.trace, 1, self.value.mul_hi(other.value))
+        }
+        fn mul_lo(self, other: Self) -> Self {
+            self.combine(other.trace, 2, self.value.mul_lo(other.value))
+        }
+        fn add(self, other: Self) -> Self {
+            self.combine(other.trace, 3, self.value.add(other.value))
+        }
+        fn sub(self, other: Self) -> Self {
+            self.combine(other.trace, 4, self.value.sub(other.value))
+        }
+        fn and(self, other: Self) -> Self {
+            self.combine(other.trace, 5, self.value.and(other.value))
+        }
+        // the shift amount is part of the divisor, so it isn't part of the trace. Shifting by a variable amount takes the same time for any amount
+        fn shr(self, shift: u32) -> Self {
+            self.combine(0, 6, self.value.shr(shift))
+        }
+    }
+
+    #[test]
+    fn test_branchfree_u32_constant_time() {
+        let max = u32::MAX;
+        let divisors = [1, 2, 3, 7, 8, 10, 1 << 31, max / 2, max - 1, max];
+        let numerators = [0, 1, 2, 7, 100, max / 2, max - 1, max];
+
+        let mut expected_traces = None;
+        for &divisor in &divisors {
+            let reduced_divisor = StrengthReducedBranchfreeU32::new(divisor);
+            for &numerator in &numerators {
+                let traced_numerator = Traced::input(numerator as u64, 1);
+                let quotient = divide_u32(traced_numerator, Traced::input(reduced_divisor.multiplier, 2), Traced::input(u32::MAX as u64, 3));
+                let remainder = remainder(traced_numerator, quotient, Traced::input(divisor as u64, 4));
+
+                // make sure that this is the same computation as the real thing, and not just the same shape
+                assert_eq!((quotient.value as u32, remainder.value as u32), StrengthReducedBranchfreeU32::div_rem(numerator, reduced_divisor));
+                assert_eq!((numerator / divisor, numerator % divisor), (quotient.value as u32, remainder.value as u32));
+
+                let traces = (quotient.trace, remainder.trace);
+                assert_eq!(*expected_traces.get_or_insert(traces), traces, "Different operations ran with numerator: {}, divisor: {}", numerator, divisor);
+            }
+        }
+    }
+
+    #[test]
+    fn test_branchfree_u64_constant_time() {
+        let max = u64::MAX;
+        let divisors = [1, 2, 3, 7, 8, 10, 1 << 63, max / 2, max / 3, max - 1, max];
+        let numerators = [0, 1, 2, 7, 100, max / 2, max - 1, max];
+
+        let mut expected_traces = None;
+        for &divisor in &divisors {
+            let reduced_divisor = StrengthReducedBranchfreeU64::new(divisor);
+            for &numerator in &numerators {
+                let traced_numerator = Traced::input(numerator, 1);
+                let quotient = divide_u64(traced_numerator, Traced::input(reduced_divisor.multiplier, 2), Traced::input(reduced_divisor.numerator_mask, 3), reduced_divisor.shift);
+                let remainder = remainder(traced_numerator, quotient, Traced::input(divisor, 4));
+
+                assert_eq!((quotient.value, remainder.value), StrengthReducedBranchfreeU64::div_rem(numerator, reduced_divisor));
+                assert_eq!((numerator / divisor, numerator % divisor), (quotient.value, remainder.value));
+
+                let traces = (quotient.trace, remainder.trace);
+                assert_eq!(*expected_traces.get_or_insert(traces), traces, "Different operations ran with numerator: {}, divisor: {}", numerator, divisor);
+            }
+        }
+    }
+}
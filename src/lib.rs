@@ -30,21 +30,612 @@
 //! There is a setup cost associated with creating stength-reduced division instances, so using strength-reduced division for 1-2 divisions is not worth the setup cost.
 //! The break-even point differs by use-case, but is typically low: Benchmarking has shown that takes 3 to 4 repeated divisions with the same StengthReduced## instance to be worth it.
 //! 
-//! `strength_reduce` is `#![no_std]`
+//! All of the constructors are `const fn`, so tables of strength-reduced divisors can be built at compile time and stored in `const` or `static` items:
+//! ```
+//! use strength_reduce::StrengthReducedU32;
+//!
+//! static BUCKET_SIZES: [StrengthReducedU32; 3] = [StrengthReducedU32::new(3), StrengthReducedU32::new(10), StrengthReducedU32::new(1000)];
+//!
+//! assert_eq!(12345 / BUCKET_SIZES[1], 1234);
+//! ```
+//!
+//! The regular divisor types branch on whether the divisor is a power of two, which is free when the divisor stays the same, but unpredictable when every division uses a different one,
+//! e.g. a table with a divisor per row. For those workloads, `StrengthReducedBranchfreeU32` and `StrengthReducedBranchfreeU64` divide by every divisor with the same fixed sequence of instructions.
+//! Because that sequence doesn't depend on the numerator or the divisor either, their `/`, `%` and `div_rem` are also suitable for constant-time code, like reducing secret values modulo a public modulus.
+//!
+//! For arithmetic modulo a runtime modulus, `ModularU32` and `ModularU64` wrap a strength-reduced modulus and provide `add_mod`, `sub_mod`, `neg_mod`, `mul_mod` and `pow_mod`.
+//! Products are computed at twice the width of the modulus, so they never overflow:
+//! ```
+//! use strength_reduce::ModularU64;
+//!
+//! let modular = ModularU64::new(1_000_000_007);
+//! assert_eq!(modular.mul_mod(123_456_789, 987_654_321), 259_106_859);
+//! assert_eq!(modular.pow_mod(2, 1_000_000_006), 1);
+//! assert_eq!(modular.sub_mod(3, 5), 1_000_000_005);
+//! ```
+//!
+//! For long chains of multiplications with a 64-bit odd modulus, like modular exponentiation, `Montgomery64` is faster.
+//! It and `Montgomery32` multiply values in Montgomery form, and use a strength-reduced modulus to convert values into it:
+//! ```
+//! use strength_reduce::Montgomery64;
+//!
+//! let montgomery = Montgomery64::new(1_000_000_007);
+//! let base = montgomery.to_montgomery(123_456_789);
+//! assert_eq!(montgomery.from_montgomery(montgomery.square(base)), 643_499_475);
+//! assert_eq!(montgomery.from_montgomery(montgomery.pow(base, 1_000_000_006)), 1);
+//! ```
+//!
+//! `strength_reduce` is `#![no_std]`. Enabling the `std` feature implements `std::error::Error` for `DivisorError`.
+//!
+//! On x86_64, enabling the `simd` feature makes the slice functions of `StrengthReducedU16`, `StrengthReducedU32` and `StrengthReducedU64` use explicit SSE2 and AVX2 kernels.
+//! AVX2 is detected at runtime if the `std` feature is also enabled, and at compile time otherwise.
+//!
+//! On nightly, enabling the `portable-simd` feature implements `Div` and `Rem` for `core::simd` vectors of `u8`, `u16`, `u32` and `u64`,
+//! so that `Simd<u32, 8> / StrengthReducedU32` divides every lane by the same divisor.
+//!
+//! Enabling the `num-bigint` feature implements `Div` and `Rem` for `BigUint` and `&BigUint` with `StrengthReducedU32` and `StrengthReducedU64` divisors,
+//! using the same limb-by-limb long division as `div_rem_limbs`. Like num-bigint's own operators for `u32` and `u64`, both return a `BigUint`. It requires the `alloc` crate.
+//!
+//! Enabling the `num-traits` feature adds the `Reducible` trait, which maps each primitive integer type to its strength-reduced divisor type
+//! and provides checked and Euclidean division against it, so that code bounded on `num_traits::PrimInt` can use strength-reduced division generically.
+//!
+//! Enabling the `serde` feature implements `Serialize` and `Deserialize` for every divisor type. Divisors are serialized as the integer they were created from,
+//! and deserializing one rejects zero. To skip recomputing the multiplier of an expensive divisor when it's deserialized, serialize it `with_multiplier` instead.
 //!
 //! The optimizations that this library provides are inherently dependent on architecture, compiler, and platform,
 //! so test before you use. 
 #![no_std]
+#![cfg_attr(feature = "portable-simd", feature(portable_simd))]
 
-#[cfg(test)]
+#[cfg(feature = "std")]
+extern crate std;
+
+#[cfg(feature = "num-bigint")]
+extern crate alloc;
+#[cfg(any(test, feature = "num-bigint"))]
 extern crate num_bigint;
+#[cfg(feature = "num-traits")]
+extern crate num_traits;
+#[cfg(feature = "serde")]
+extern crate serde;
 #[cfg(test)]
 extern crate rand;
 
-use core::ops::{Div, Rem};
+use core::cmp::Ordering;
+use core::convert::TryFrom;
+use core::fmt;
+use core::hash::{Hash, Hasher};
+use core::num::{NonZeroU8, NonZeroU16, NonZeroU32, NonZeroU64, NonZeroU128, NonZeroUsize};
+use core::num::{NonZeroI8, NonZeroI16, NonZeroI32, NonZeroI64, NonZeroI128, NonZeroIsize};
+use core::num::Wrapping;
+use core::ops::{Div, Rem, DivAssign, RemAssign};
+use core::ptr;
 
 mod long_division;
 mod long_multiplication;
+mod modular_inverse;
+mod signed;
+mod branchfree;
+mod modular;
+mod montgomery;
+#[cfg(all(feature = "simd", target_arch = "x86_64"))]
+mod simd_x86_64;
+#[cfg(feature = "portable-simd")]
+mod portable_simd;
+#[cfg(feature = "num-bigint")]
+mod biguint;
+#[cfg(feature = "num-traits")]
+mod reducible;
+#[cfg(feature = "serde")]
+mod serialization;
+
+pub use signed::{StrengthReducedI8, StrengthReducedI16, StrengthReducedI32, StrengthReducedI64, StrengthReducedI128, StrengthReducedIsize};
+pub use branchfree::{StrengthReducedBranchfreeU32, StrengthReducedBranchfreeU64};
+pub use modular::{ModularU32, ModularU64};
+pub use montgomery::{Montgomery32, Montgomery64};
+#[cfg(feature = "num-traits")]
+pub use reducible::Reducible;
+#[cfg(feature = "serde")]
+pub use serialization::with_multiplier;
+
+/// Common interface implemented by every strength-reduced divisor type, so that code can be written once for all integer widths.
+///
+/// The `/` and `%` operators are available through the bounds on `Int`.
+///
+/// # Example:
+/// ```
+/// use strength_reduce::{StrengthReduced, StrengthReducedU32, StrengthReducedI64};
+///
+/// fn bucketize<D: StrengthReduced>(values: &[D::Int], bucket_size: D) -> Vec<D::Int> {
+///     values.iter().map(|&value| value / bucket_size).collect()
+/// }
+///
+/// assert_eq!(bucketize(&[0u32, 9, 10, 25], StrengthReducedU32::new(10)), vec![0, 0, 1, 2]);
+/// assert_eq!(bucketize(&[-25i64, 25], StrengthReducedI64::new(10)), vec![-2, 2]);
+/// ```
+pub trait StrengthReduced: Copy + core::fmt::Debug {
+    /// The primitive integer type that this divisor operates on.
+    type Int: Copy + Div<Self, Output = Self::Int> + Rem<Self, Output = Self::Int>;
+
+    /// Creates a new divisor instance.
+    ///
+    /// # Panics:
+    ///
+    /// Panics if `divisor` is 0
+    fn new(divisor: Self::Int) -> Self;
+
+    /// Creates a new divisor instance, returning an error instead of panicking if `divisor` can't be used.
+    fn try_new(divisor: Self::Int) -> Result<Self, DivisorError>;
+
+    /// Retrieve the value used to create this struct
+    fn get(&self) -> Self::Int;
+
+    /// Simultaneous truncated integer division and modulus.
+    /// Returns `(quotient, remainder)`.
+    fn div_rem(numerator: Self::Int, denom: Self) -> (Self::Int, Self::Int);
+
+    /// Division that rounds the quotient toward negative infinity.
+    fn div_floor(numerator: Self::Int, denom: Self) -> Self::Int;
+
+    /// Division that rounds the quotient toward positive infinity.
+    fn div_ceil(numerator: Self::Int, denom: Self) -> Self::Int;
+
+    /// Division that rounds the quotient to the nearest integer, with ties rounded away from zero.
+    fn div_round(numerator: Self::Int, denom: Self) -> Self::Int;
+
+    /// Euclidean division: the quotient that makes `rem_euclid` non-negative.
+    fn div_euclid(numerator: Self::Int, denom: Self) -> Self::Int;
+
+    /// Euclidean remainder, which is always non-negative.
+    fn rem_euclid(numerator: Self::Int, denom: Self) -> Self::Int;
+}
+
+/// The error returned when a strength-reduced divisor, or the modulus of a modular arithmetic type, can't be created from a value.
+///
+/// Every `try_new` can return `Zero`. `Even` is only returned by `Montgomery32::try_new` and `Montgomery64::try_new`, because Montgomery multiplication needs an odd modulus.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum DivisorError {
+    /// The divisor or modulus was zero.
+    Zero,
+    /// The modulus was even, which Montgomery multiplication doesn't support.
+    Even,
+}
+
+impl fmt::Display for DivisorError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            DivisorError::Zero => f.write_str("cannot create a strength-reduced divisor from zero"),
+            DivisorError::Even => f.write_str("cannot create a Montgomery context from an even modulus"),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for DivisorError {}
+
+// Everything in here only depends on new(), get() and div_rem(), so it's shared by every divisor type.
+// The trait impl forwards to the inherent methods, which are what actually get called when the concrete type is known
+macro_rules! impl_strength_reduced {
+    ($struct_name:ident, $primitive_type:ident, $nonzero_type:ident) => (
+        impl $struct_name {
+            /// Creates a new divisor instance, returning an error instead of panicking if `divisor` is 0.
+            #[inline]
+            pub const fn try_new(divisor: $primitive_type) -> Result<Self, DivisorError> {
+                if divisor == 0 {
+                    Err(DivisorError::Zero)
+                } else {
+                    Ok(Self::new(divisor))
+                }
+            }
+        }
+
+        impl From<$nonzero_type> for $struct_name {
+            #[inline]
+            fn from(divisor: $nonzero_type) -> Self {
+                Self::new(divisor.get())
+            }
+        }
+
+        impl TryFrom<$primitive_type> for $struct_name {
+            type Error = DivisorError;
+
+            #[inline]
+            fn try_from(divisor: $primitive_type) -> Result<Self, DivisorError> {
+                Self::try_new(divisor)
+            }
+        }
+
+        // Everything else is derived from the divisor, so comparing and hashing the divisor alone is enough, and a lot cheaper than comparing the multiplier too
+        impl PartialEq for $struct_name {
+            #[inline]
+            fn eq(&self, other: &Self) -> bool {
+                self.get() == other.get()
+            }
+        }
+
+        impl Eq for $struct_name {}
+
+        impl PartialOrd for $struct_name {
+            #[inline]
+            fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+                Some(self.cmp(other))
+            }
+        }
+
+        impl Ord for $struct_name {
+            #[inline]
+            fn cmp(&self, other: &Self) -> Ordering {
+                self.get().cmp(&other.get())
+            }
+        }
+
+        impl Hash for $struct_name {
+            #[inline]
+            fn hash<H: Hasher>(&self, state: &mut H) {
+                self.get().hash(state)
+            }
+        }
+
+        impl StrengthReduced for $struct_name {
+            type Int = $primitive_type;
+
+            #[inline]
+            fn new(divisor: $primitive_type) -> Self {
+                $struct_name::new(divisor)
+            }
+
+            #[inline]
+            fn try_new(divisor: $primitive_type) -> Result<Self, DivisorError> {
+                $struct_name::try_new(divisor)
+            }
+
+            #[inline]
+            fn get(&self) -> $primitive_type {
+                $struct_name::get(self)
+            }
+
+            #[inline]
+            fn div_rem(numerator: $primitive_type, denom: Self) -> ($primitive_type, $primitive_type) {
+                $struct_name::div_rem(numerator, denom)
+            }
+
+            #[inline]
+            fn div_floor(numerator: $primitive_type, denom: Self) -> $primitive_type {
+                $struct_name::div_floor(numerator, denom)
+            }
+
+            #[inline]
+            fn div_ceil(numerator: $primitive_type, denom: Self) -> $primitive_type {
+                $struct_name::div_ceil(numerator, denom)
+            }
+
+            #[inline]
+            fn div_round(numerator: $primitive_type, denom: Self) -> $primitive_type {
+                $struct_name::div_round(numerator, denom)
+            }
+
+            #[inline]
+            fn div_euclid(numerator: $primitive_type, denom: Self) -> $primitive_type {
+                $struct_name::div_euclid(numerator, denom)
+            }
+
+            #[inline]
+            fn rem_euclid(numerator: $primitive_type, denom: Self) -> $primitive_type {
+                $struct_name::rem_euclid(numerator, denom)
+            }
+        }
+    )
+}
+
+// The by-value operators are implemented alongside each type. These are the other forms, which all forward to them
+macro_rules! impl_operator_forms {
+    ($struct_name:ident, $primitive_type:ident) => (
+        impl<'a> Div<&'a $struct_name> for $primitive_type {
+            type Output = $primitive_type;
+
+            #[inline]
+            fn div(self, rhs: &'a $struct_name) -> Self::Output {
+                self / *rhs
+            }
+        }
+
+        impl<'a> Div<$struct_name> for &'a $primitive_type {
+            type Output = $primitive_type;
+
+            #[inline]
+            fn div(self, rhs: $struct_name) -> Self::Output {
+                *self / rhs
+            }
+        }
+
+        impl<'a, 'b> Div<&'b $struct_name> for &'a $primitive_type {
+            type Output = $primitive_type;
+
+            #[inline]
+            fn div(self, rhs: &'b $struct_name) -> Self::Output {
+                *self / *rhs
+            }
+        }
+
+        impl<'a> Rem<&'a $struct_name> for $primitive_type {
+            type Output = $primitive_type;
+
+            #[inline]
+            fn rem(self, rhs: &'a $struct_name) -> Self::Output {
+                self % *rhs
+            }
+        }
+
+        impl<'a> Rem<$struct_name> for &'a $primitive_type {
+            type Output = $primitive_type;
+
+            #[inline]
+            fn rem(self, rhs: $struct_name) -> Self::Output {
+                *self % rhs
+            }
+        }
+
+        impl<'a, 'b> Rem<&'b $struct_name> for &'a $primitive_type {
+            type Output = $primitive_type;
+
+            #[inline]
+            fn rem(self, rhs: &'b $struct_name) -> Self::Output {
+                *self % *rhs
+            }
+        }
+
+        impl DivAssign<$struct_name> for $primitive_type {
+            #[inline]
+            fn div_assign(&mut self, rhs: $struct_name) {
+                *self = *self / rhs;
+            }
+        }
+
+        impl<'a> DivAssign<&'a $struct_name> for $primitive_type {
+            #[inline]
+            fn div_assign(&mut self, rhs: &'a $struct_name) {
+                *self = *self / *rhs;
+            }
+        }
+
+        impl RemAssign<$struct_name> for $primitive_type {
+            #[inline]
+            fn rem_assign(&mut self, rhs: $struct_name) {
+                *self = *self % rhs;
+            }
+        }
+
+        impl<'a> RemAssign<&'a $struct_name> for $primitive_type {
+            #[inline]
+            fn rem_assign(&mut self, rhs: &'a $struct_name) {
+                *self = *self % *rhs;
+            }
+        }
+    )
+}
+
+// Unsigned division can't overflow, so dividing a Wrapping numerator is the same as dividing the integer inside it.
+// A nonzero numerator can still have a zero quotient or remainder, so those produce plain integers
+macro_rules! impl_unsigned_numerator_types {
+    ($struct_name:ident, $primitive_type:ident, $nonzero_type:ident) => (
+        impl Div<$struct_name> for Wrapping<$primitive_type> {
+            type Output = Wrapping<$primitive_type>;
+
+            #[inline]
+            fn div(self, rhs: $struct_name) -> Self::Output {
+                Wrapping(self.0 / rhs)
+            }
+        }
+
+        impl Rem<$struct_name> for Wrapping<$primitive_type> {
+            type Output = Wrapping<$primitive_type>;
+
+            #[inline]
+            fn rem(self, rhs: $struct_name) -> Self::Output {
+                Wrapping(self.0 % rhs)
+            }
+        }
+
+        impl DivAssign<$struct_name> for Wrapping<$primitive_type> {
+            #[inline]
+            fn div_assign(&mut self, rhs: $struct_name) {
+                self.0 /= rhs;
+            }
+        }
+
+        impl RemAssign<$struct_name> for Wrapping<$primitive_type> {
+            #[inline]
+            fn rem_assign(&mut self, rhs: $struct_name) {
+                self.0 %= rhs;
+            }
+        }
+
+        impl Div<$struct_name> for $nonzero_type {
+            type Output = $primitive_type;
+
+            #[inline]
+            fn div(self, rhs: $struct_name) -> Self::Output {
+                self.get() / rhs
+            }
+        }
+
+        impl Rem<$struct_name> for $nonzero_type {
+            type Output = $primitive_type;
+
+            #[inline]
+            fn rem(self, rhs: $struct_name) -> Self::Output {
+                self.get() % rhs
+            }
+        }
+    )
+}
+
+// The slice functions test for a power of two once, up front, so that each loop body is a fixed sequence of arithmetic that the compiler can vectorize.
+// Types with explicit SIMD kernels hand as many elements as they can to div_rem_slice_simd first, and the scalar loop picks up the rest.
+macro_rules! impl_unsigned_slice_ops {
+    ($struct_name:ident, $primitive_type:ident) => (
+        impl $struct_name {
+            /// Divides every element of `numerators` by the divisor, storing the results in `quotients`.
+            ///
+            /// # Panics:
+            ///
+            /// Panics if `numerators` and `quotients` have different lengths
+            #[inline]
+            pub fn div_slice(numerators: &[$primitive_type], denom: Self, quotients: &mut [$primitive_type]) {
+                assert_eq!(numerators.len(), quotients.len(), "numerators and quotients must have the same length");
+
+                if denom.divisor.is_power_of_two() {
+                    let shift = denom.divisor.trailing_zeros();
+                    for (quotient, &numerator) in quotients.iter_mut().zip(numerators) {
+                        *quotient = numerator >> shift;
+                    }
+                } else {
+                    let processed = unsafe { Self::div_rem_slice_simd(numerators.as_ptr(), quotients.as_mut_ptr(), ptr::null_mut(), numerators.len(), denom) };
+                    for (quotient, &numerator) in quotients[processed..].iter_mut().zip(&numerators[processed..]) {
+                        *quotient = Self::div_non_power_of_two(numerator, denom);
+                    }
+                }
+            }
+
+            /// Computes the remainder of every element of `numerators` divided by the divisor, storing the results in `remainders`.
+            ///
+            /// # Panics:
+            ///
+            /// Panics if `numerators` and `remainders` have different lengths
+            #[inline]
+            pub fn rem_slice(numerators: &[$primitive_type], denom: Self, remainders: &mut [$primitive_type]) {
+                assert_eq!(numerators.len(), remainders.len(), "numerators and remainders must have the same length");
+
+                if denom.divisor.is_power_of_two() {
+                    let mask = denom.divisor - 1;
+                    for (remainder, &numerator) in remainders.iter_mut().zip(numerators) {
+                        *remainder = numerator & mask;
+                    }
+                } else {
+                    let processed = unsafe { Self::div_rem_slice_simd(numerators.as_ptr(), ptr::null_mut(), remainders.as_mut_ptr(), numerators.len(), denom) };
+                    for (remainder, &numerator) in remainders[processed..].iter_mut().zip(&numerators[processed..]) {
+                        *remainder = Self::rem_non_power_of_two(numerator, denom);
+                    }
+                }
+            }
+
+            /// Simultaneous truncated division and modulus of every element of `numerators`, storing the results in `quotients` and `remainders`.
+            ///
+            /// # Panics:
+            ///
+            /// Panics if `numerators`, `quotients` and `remainders` don't all have the same length
+            #[inline]
+            pub fn div_rem_slice(numerators: &[$primitive_type], denom: Self, quotients: &mut [$primitive_type], remainders: &mut [$primitive_type]) {
+                assert_eq!(numerators.len(), quotients.len(), "numerators and quotients must have the same length");
+                assert_eq!(numerators.len(), remainders.len(), "numerators and remainders must have the same length");
+
+                if denom.divisor.is_power_of_two() {
+                    let shift = denom.divisor.trailing_zeros();
+                    let mask = denom.divisor - 1;
+                    for ((quotient, remainder), &numerator) in quotients.iter_mut().zip(remainders.iter_mut()).zip(numerators) {
+                        *quotient = numerator >> shift;
+                        *remainder = numerator & mask;
+                    }
+                } else {
+                    let processed = unsafe { Self::div_rem_slice_simd(numerators.as_ptr(), quotients.as_mut_ptr(), remainders.as_mut_ptr(), numerators.len(), denom) };
+                    for ((quotient, remainder), &numerator) in quotients[processed..].iter_mut().zip(remainders[processed..].iter_mut()).zip(&numerators[processed..]) {
+                        let divided = Self::div_non_power_of_two(numerator, denom);
+                        *quotient = divided;
+                        *remainder = numerator - divided * denom.divisor;
+                    }
+                }
+            }
+
+            /// Divides every element of `values` by the divisor, in place.
+            #[inline]
+            pub fn div_slice_in_place(values: &mut [$primitive_type], denom: Self) {
+                if denom.divisor.is_power_of_two() {
+                    let shift = denom.divisor.trailing_zeros();
+                    for value in values.iter_mut() {
+                        *value >>= shift;
+                    }
+                } else {
+                    let values_ptr = values.as_mut_ptr();
+                    let processed = unsafe { Self::div_rem_slice_simd(values_ptr, values_ptr, ptr::null_mut(), values.len(), denom) };
+                    for value in values[processed..].iter_mut() {
+                        *value = Self::div_non_power_of_two(*value, denom);
+                    }
+                }
+            }
+
+            /// Replaces every element of `values` with its remainder when divided by the divisor, in place.
+            #[inline]
+            pub fn rem_slice_in_place(values: &mut [$primitive_type], denom: Self) {
+                if denom.divisor.is_power_of_two() {
+                    let mask = denom.divisor - 1;
+                    for value in values.iter_mut() {
+                        *value &= mask;
+                    }
+                } else {
+                    let values_ptr = values.as_mut_ptr();
+                    let processed = unsafe { Self::div_rem_slice_simd(values_ptr, ptr::null_mut(), values_ptr, values.len(), denom) };
+                    for value in values[processed..].iter_mut() {
+                        *value = Self::rem_non_power_of_two(*value, denom);
+                    }
+                }
+            }
+        }
+    )
+}
+
+// Types without SIMD kernels leave every element to the scalar loop
+macro_rules! impl_no_simd_slice_kernel {
+    ($struct_name:ident, $primitive_type:ident) => (
+        impl $struct_name {
+            #[inline(always)]
+            unsafe fn div_rem_slice_simd(_numerators: *const $primitive_type, _quotients: *mut $primitive_type, _remainders: *mut $primitive_type, _len: usize, _denom: Self) -> usize {
+                0
+            }
+        }
+    )
+}
+
+// The rounding modes all start from the truncated quotient and remainder, and use the remainder to decide whether to round the quotient up.
+// For unsigned integers, truncating, flooring and euclidean division are all the same thing.
+macro_rules! impl_unsigned_division_modes {
+    ($struct_name:ident, $primitive_type:ident) => (
+        impl $struct_name {
+            /// Division that rounds the quotient toward negative infinity. For unsigned integers, this is the same as `/`.
+            #[inline]
+            pub fn div_floor(numerator: $primitive_type, denom: Self) -> $primitive_type {
+                numerator / denom
+            }
+
+            /// Division that rounds the quotient toward positive infinity.
+            #[inline]
+            pub fn div_ceil(numerator: $primitive_type, denom: Self) -> $primitive_type {
+                let (quotient, remainder) = Self::div_rem(numerator, denom);
+                quotient + (remainder != 0) as $primitive_type
+            }
+
+            /// Division that rounds the quotient to the nearest integer, with ties rounded up.
+            #[inline]
+            pub fn div_round(numerator: $primitive_type, denom: Self) -> $primitive_type {
+                let (quotient, remainder) = Self::div_rem(numerator, denom);
+                // round up if remainder >= divisor / 2, written so that it can't overflow
+                quotient + (remainder >= denom.get() - remainder) as $primitive_type
+            }
+
+            /// Euclidean division. For unsigned integers, this is the same as `/`.
+            #[inline]
+            pub fn div_euclid(numerator: $primitive_type, denom: Self) -> $primitive_type {
+                numerator / denom
+            }
+
+            /// Euclidean remainder. For unsigned integers, this is the same as `%`.
+            #[inline]
+            pub fn rem_euclid(numerator: $primitive_type, denom: Self) -> $primitive_type {
+                numerator % denom
+            }
+        }
+    )
+}
 
 /// Implements unsigned division and modulo via mutiplication and shifts.
 ///
@@ -54,6 +645,8 @@ mod long_multiplication;
 pub struct StrengthReducedU8 {
     multiplier: u16,
     divisor: u8,
+    inverse: u8,
+    max_quotient: u8,
 }
 impl StrengthReducedU8 {
     /// Creates a new divisor instance.
@@ -64,14 +657,31 @@ impl StrengthReducedU8 {
     /// 
     /// Panics if `divisor` is 0
     #[inline]
-    pub fn new(divisor: u8) -> Self {
+    pub const fn new(divisor: u8) -> Self {
         assert!(divisor > 0);
 
         if divisor.is_power_of_two() { 
-            Self{ multiplier: 0, divisor }
+            Self{ multiplier: 0, divisor, inverse: 1, max_quotient: u8::MAX >> divisor.trailing_zeros() }
+        } else {
+            let divided = u16::MAX / (divisor as u16);
+            let inverse = modular_inverse::modular_inverse_u8(divisor >> divisor.trailing_zeros());
+            Self{ multiplier: divided + 1, divisor, inverse, max_quotient: (divided >> 8) as u8 }
+        }
+    }
+
+    // Rebuilds a divisor from a multiplier that was computed elsewhere, e.g. one that was deserialized. Returns None if it isn't the multiplier new() would compute.
+    // The multiplier is ceil(2^16 / divisor), so checking it only takes two multiplications: multiplier * divisor overflows, but (multiplier - 1) * divisor doesn't
+    #[cfg(feature = "serde")]
+    pub(crate) fn from_multiplier(divisor: u8, multiplier: u16) -> Option<Self> {
+        if divisor == 0 {
+            None
+        } else if divisor.is_power_of_two() {
+            if multiplier == 0 { Some(Self::new(divisor)) } else { None }
+        } else if multiplier.checked_mul(divisor as u16).is_some() || (multiplier - 1).checked_mul(divisor as u16).is_none() {
+            None
         } else {
-            let divided = core::u16::MAX / (divisor as u16);
-            Self{ multiplier: divided + 1, divisor }
+            let inverse = modular_inverse::modular_inverse_u8(divisor >> divisor.trailing_zeros());
+            Some(Self{ multiplier, divisor, inverse, max_quotient: ((multiplier - 1) >> 8) as u8 })
         }
     }
 
@@ -84,6 +694,42 @@ impl StrengthReducedU8 {
         (quotient, remainder)
     }
 
+    /// Returns true if `numerator` is a multiple of the divisor, ie if `numerator % divisor == 0`.
+    ///
+    /// This is faster than computing the remainder: it only takes a multiplication, a rotation, and a comparison.
+    #[inline]
+    pub fn is_multiple_of(numerator: u8, denom: Self) -> bool {
+        numerator.wrapping_mul(denom.inverse).rotate_right(denom.divisor.trailing_zeros()) <= denom.max_quotient
+    }
+
+    /// Returns true if this divisor divides `numerator` evenly. Equivalent to `StrengthReducedU8::is_multiple_of(numerator, divisor)`
+    #[inline]
+    pub fn divides(&self, numerator: u8) -> bool {
+        Self::is_multiple_of(numerator, *self)
+    }
+
+    /// Divides `numerator` by the divisor, assuming that `numerator` is an exact multiple of the divisor.
+    ///
+    /// This only takes a shift and a multiplication, which is cheaper than a regular division.
+    /// If `numerator` isn't a multiple of the divisor, the returned value is meaningless. Use `checked_divide_exact` when that can't be ruled out.
+    ///
+    /// # Panics:
+    ///
+    /// In debug builds, panics if `numerator` isn't a multiple of the divisor
+    #[inline]
+    pub fn divide_exact(numerator: u8, denom: Self) -> u8 {
+        debug_assert!(Self::is_multiple_of(numerator, denom), "divide_exact called with a numerator that isn't a multiple of the divisor");
+        (numerator >> denom.divisor.trailing_zeros()).wrapping_mul(denom.inverse)
+    }
+
+    /// Divides `numerator` by the divisor if `numerator` is an exact multiple of the divisor. Returns `None` otherwise.
+    #[inline]
+    pub fn checked_divide_exact(numerator: u8, denom: Self) -> Option<u8> {
+        // For multiples of the divisor, the value that is_multiple_of compares against the bound is exactly the quotient
+        let quotient = numerator.wrapping_mul(denom.inverse).rotate_right(denom.divisor.trailing_zeros());
+        if quotient <= denom.max_quotient { Some(quotient) } else { None }
+    }
+
     /// Retrieve the value used to create this struct
     #[inline]
     pub fn get(&self) -> u8 {
@@ -91,6 +737,27 @@ impl StrengthReducedU8 {
     }
 }
 
+// Division and remainder for divisors that aren't powers of two. These are split out so that the slice functions can test for a power of two once, outside the loop
+impl StrengthReducedU8 {
+    #[inline(always)]
+    fn div_non_power_of_two(numerator: u8, denom: Self) -> u8 {
+        let numerator = numerator as u16;
+        let multiplied_hi = numerator * (denom.multiplier >> 8);
+        let multiplied_lo = (numerator * denom.multiplier as u8 as u16) >> 8;
+
+        ((multiplied_hi + multiplied_lo) >> 8) as u8
+    }
+
+    #[inline(always)]
+    fn rem_non_power_of_two(numerator: u8, denom: Self) -> u8 {
+        let product = denom.multiplier.wrapping_mul(numerator as u16) as u32;
+        let divisor = denom.divisor as u32;
+
+        let shifted = (product * divisor) >> 16;
+        shifted as u8
+    }
+}
+
 impl Div<StrengthReducedU8> for u8 {
     type Output = u8;
 
@@ -99,11 +766,7 @@ impl Div<StrengthReducedU8> for u8 {
         if rhs.multiplier == 0 {
             (self as u16 >> rhs.divisor.trailing_zeros()) as u8
         } else {
-            let numerator = self as u16;
-            let multiplied_hi = numerator * (rhs.multiplier >> 8);
-            let multiplied_lo = (numerator * rhs.multiplier as u8 as u16) >> 8;
-
-            ((multiplied_hi + multiplied_lo) >> 8) as u8
+            StrengthReducedU8::div_non_power_of_two(self, rhs)
         }
     }
 }
@@ -116,11 +779,7 @@ impl Rem<StrengthReducedU8> for u8 {
         if rhs.multiplier == 0 {
             self & (rhs.divisor - 1)
         } else {
-            let product = rhs.multiplier.wrapping_mul(self as u16) as u32;
-            let divisor = rhs.divisor as u32;
-
-            let shifted = (product * divisor) >> 16;
-            shifted as u8
+            StrengthReducedU8::rem_non_power_of_two(self, rhs)
         }
     }
 }
@@ -136,6 +795,8 @@ macro_rules! strength_reduced_u16 {
         pub struct $struct_name {
             multiplier: u32,
             divisor: $primitive_type,
+            inverse: $primitive_type,
+            max_quotient: $primitive_type,
         }
         impl $struct_name {
             /// Creates a new divisor instance.
@@ -146,14 +807,30 @@ macro_rules! strength_reduced_u16 {
             /// 
             /// Panics if `divisor` is 0
             #[inline]
-            pub fn new(divisor: $primitive_type) -> Self {
+            pub const fn new(divisor: $primitive_type) -> Self {
                 assert!(divisor > 0);
 
                 if divisor.is_power_of_two() { 
-                    Self{ multiplier: 0, divisor }
+                    Self{ multiplier: 0, divisor, inverse: 1, max_quotient: $primitive_type::MAX >> divisor.trailing_zeros() }
+                } else {
+                    let divided = u32::MAX / (divisor as u32);
+                    let inverse = modular_inverse::modular_inverse_u16((divisor >> divisor.trailing_zeros()) as u16) as $primitive_type;
+                    Self{ multiplier: divided + 1, divisor, inverse, max_quotient: (divided >> 16) as $primitive_type }
+                }
+            }
+
+            // Same check as StrengthReducedU8::from_multiplier: the multiplier is ceil(2^32 / divisor)
+            #[cfg(feature = "serde")]
+            pub(crate) fn from_multiplier(divisor: $primitive_type, multiplier: u32) -> Option<Self> {
+                if divisor == 0 {
+                    None
+                } else if divisor.is_power_of_two() {
+                    if multiplier == 0 { Some(Self::new(divisor)) } else { None }
+                } else if multiplier.checked_mul(divisor as u32).is_some() || (multiplier - 1).checked_mul(divisor as u32).is_none() {
+                    None
                 } else {
-                    let divided = core::u32::MAX / (divisor as u32);
-                    Self{ multiplier: divided + 1, divisor }
+                    let inverse = modular_inverse::modular_inverse_u16((divisor >> divisor.trailing_zeros()) as u16) as $primitive_type;
+                    Some(Self{ multiplier, divisor, inverse, max_quotient: ((multiplier - 1) >> 16) as $primitive_type })
                 }
             }
 
@@ -166,6 +843,42 @@ macro_rules! strength_reduced_u16 {
                 (quotient, remainder)
             }
 
+            /// Returns true if `numerator` is a multiple of the divisor, ie if `numerator % divisor == 0`.
+            ///
+            /// This is faster than computing the remainder: it only takes a multiplication, a rotation, and a comparison.
+            #[inline]
+            pub fn is_multiple_of(numerator: $primitive_type, denom: Self) -> bool {
+                numerator.wrapping_mul(denom.inverse).rotate_right(denom.divisor.trailing_zeros()) <= denom.max_quotient
+            }
+
+            /// Returns true if this divisor divides `numerator` evenly. Equivalent to `is_multiple_of(numerator, divisor)`
+            #[inline]
+            pub fn divides(&self, numerator: $primitive_type) -> bool {
+                Self::is_multiple_of(numerator, *self)
+            }
+
+            /// Divides `numerator` by the divisor, assuming that `numerator` is an exact multiple of the divisor.
+            ///
+            /// This only takes a shift and a multiplication, which is cheaper than a regular division.
+            /// If `numerator` isn't a multiple of the divisor, the returned value is meaningless. Use `checked_divide_exact` when that can't be ruled out.
+            ///
+            /// # Panics:
+            ///
+            /// In debug builds, panics if `numerator` isn't a multiple of the divisor
+            #[inline]
+            pub fn divide_exact(numerator: $primitive_type, denom: Self) -> $primitive_type {
+                debug_assert!(Self::is_multiple_of(numerator, denom), "divide_exact called with a numerator that isn't a multiple of the divisor");
+                (numerator >> denom.divisor.trailing_zeros()).wrapping_mul(denom.inverse)
+            }
+
+            /// Divides `numerator` by the divisor if `numerator` is an exact multiple of the divisor. Returns `None` otherwise.
+            #[inline]
+            pub fn checked_divide_exact(numerator: $primitive_type, denom: Self) -> Option<$primitive_type> {
+                // For multiples of the divisor, the value that is_multiple_of compares against the bound is exactly the quotient
+                let quotient = numerator.wrapping_mul(denom.inverse).rotate_right(denom.divisor.trailing_zeros());
+                if quotient <= denom.max_quotient { Some(quotient) } else { None }
+            }
+
             /// Retrieve the value used to create this struct
             #[inline]
             pub fn get(&self) -> $primitive_type {
@@ -173,6 +886,26 @@ macro_rules! strength_reduced_u16 {
             }
         }
 
+        // Division and remainder for divisors that aren't powers of two. These are split out so that the slice functions can test for a power of two once, outside the loop
+        impl $struct_name {
+            #[inline(always)]
+            fn div_non_power_of_two(numerator: $primitive_type, denom: Self) -> $primitive_type {
+                let numerator = numerator as u32;
+                let multiplied_hi = numerator * (denom.multiplier >> 16);
+                let multiplied_lo = (numerator * denom.multiplier as u16 as u32) >> 16;
+
+                ((multiplied_hi + multiplied_lo) >> 16) as $primitive_type
+            }
+
+            // Unlike StrengthReducedU8 and StrengthReducedU32, this multiplies the quotient back rather than computing the remainder directly from the lower half of multiplier * numerator.
+            // The direct remainder multiplies that 32-bit lower half by the divisor in 64 bits, which is slower, in loops and in rem_slice. repeated_modulo_direct and slice_modulo_direct in the benchmarks compare the two
+            #[inline(always)]
+            fn rem_non_power_of_two(numerator: $primitive_type, denom: Self) -> $primitive_type {
+                let quotient = Self::div_non_power_of_two(numerator, denom);
+                numerator - quotient * denom.divisor
+            }
+        }
+
         impl Div<$struct_name> for $primitive_type {
             type Output = $primitive_type;
 
@@ -181,11 +914,7 @@ macro_rules! strength_reduced_u16 {
                 if rhs.multiplier == 0 {
                     self >> rhs.divisor.trailing_zeros()
                 } else {
-                    let numerator = self as u32;
-                    let multiplied_hi = numerator * (rhs.multiplier >> 16);
-                    let multiplied_lo = (numerator * rhs.multiplier as u16 as u32) >> 16;
-
-                    ((multiplied_hi + multiplied_lo) >> 16) as $primitive_type
+                    $struct_name::div_non_power_of_two(self, rhs)
                 }
             }
         }
@@ -198,8 +927,7 @@ macro_rules! strength_reduced_u16 {
                 if rhs.multiplier == 0 {
                     self & (rhs.divisor - 1)
                 } else {
-                    let quotient = self / rhs;
-                    self - quotient * rhs.divisor
+                    $struct_name::rem_non_power_of_two(self, rhs)
                 }
             }
         }
@@ -217,6 +945,8 @@ macro_rules! strength_reduced_u32 {
         pub struct $struct_name {
             multiplier: u64,
             divisor: $primitive_type,
+            inverse: $primitive_type,
+            max_quotient: $primitive_type,
         }
         impl $struct_name {
             /// Creates a new divisor instance.
@@ -227,14 +957,30 @@ macro_rules! strength_reduced_u32 {
             /// 
             /// Panics if `divisor` is 0
             #[inline]
-            pub fn new(divisor: $primitive_type) -> Self {
+            pub const fn new(divisor: $primitive_type) -> Self {
                 assert!(divisor > 0);
 
                 if divisor.is_power_of_two() { 
-                    Self{ multiplier: 0, divisor }
+                    Self{ multiplier: 0, divisor, inverse: 1, max_quotient: $primitive_type::MAX >> divisor.trailing_zeros() }
+                } else {
+                    let divided = u64::MAX / (divisor as u64);
+                    let inverse = modular_inverse::modular_inverse_u32((divisor >> divisor.trailing_zeros()) as u32) as $primitive_type;
+                    Self{ multiplier: divided + 1, divisor, inverse, max_quotient: (divided >> 32) as $primitive_type }
+                }
+            }
+
+            // Same check as StrengthReducedU8::from_multiplier: the multiplier is ceil(2^64 / divisor)
+            #[cfg(feature = "serde")]
+            pub(crate) fn from_multiplier(divisor: $primitive_type, multiplier: u64) -> Option<Self> {
+                if divisor == 0 {
+                    None
+                } else if divisor.is_power_of_two() {
+                    if multiplier == 0 { Some(Self::new(divisor)) } else { None }
+                } else if multiplier.checked_mul(divisor as u64).is_some() || (multiplier - 1).checked_mul(divisor as u64).is_none() {
+                    None
                 } else {
-                    let divided = core::u64::MAX / (divisor as u64);
-                    Self{ multiplier: divided + 1, divisor }
+                    let inverse = modular_inverse::modular_inverse_u32((divisor >> divisor.trailing_zeros()) as u32) as $primitive_type;
+                    Some(Self{ multiplier, divisor, inverse, max_quotient: ((multiplier - 1) >> 32) as $primitive_type })
                 }
             }
 
@@ -256,10 +1002,67 @@ macro_rules! strength_reduced_u32 {
                 }
             }
 
-            /// Retrieve the value used to create this struct
-            #[inline]
-            pub fn get(&self) -> $primitive_type {
-                self.divisor
+            /// Returns true if `numerator` is a multiple of the divisor, ie if `numerator % divisor == 0`.
+            ///
+            /// This is faster than computing the remainder: it only takes a multiplication, a rotation, and a comparison.
+            #[inline]
+            pub fn is_multiple_of(numerator: $primitive_type, denom: Self) -> bool {
+                numerator.wrapping_mul(denom.inverse).rotate_right(denom.divisor.trailing_zeros()) <= denom.max_quotient
+            }
+
+            /// Returns true if this divisor divides `numerator` evenly. Equivalent to `is_multiple_of(numerator, divisor)`
+            #[inline]
+            pub fn divides(&self, numerator: $primitive_type) -> bool {
+                Self::is_multiple_of(numerator, *self)
+            }
+
+            /// Divides `numerator` by the divisor, assuming that `numerator` is an exact multiple of the divisor.
+            ///
+            /// This only takes a shift and a multiplication, which is cheaper than a regular division.
+            /// If `numerator` isn't a multiple of the divisor, the returned value is meaningless. Use `checked_divide_exact` when that can't be ruled out.
+            ///
+            /// # Panics:
+            ///
+            /// In debug builds, panics if `numerator` isn't a multiple of the divisor
+            #[inline]
+            pub fn divide_exact(numerator: $primitive_type, denom: Self) -> $primitive_type {
+                debug_assert!(Self::is_multiple_of(numerator, denom), "divide_exact called with a numerator that isn't a multiple of the divisor");
+                (numerator >> denom.divisor.trailing_zeros()).wrapping_mul(denom.inverse)
+            }
+
+            /// Divides `numerator` by the divisor if `numerator` is an exact multiple of the divisor. Returns `None` otherwise.
+            #[inline]
+            pub fn checked_divide_exact(numerator: $primitive_type, denom: Self) -> Option<$primitive_type> {
+                // For multiples of the divisor, the value that is_multiple_of compares against the bound is exactly the quotient
+                let quotient = numerator.wrapping_mul(denom.inverse).rotate_right(denom.divisor.trailing_zeros());
+                if quotient <= denom.max_quotient { Some(quotient) } else { None }
+            }
+
+            /// Retrieve the value used to create this struct
+            #[inline]
+            pub fn get(&self) -> $primitive_type {
+                self.divisor
+            }
+        }
+
+        // Division and remainder for divisors that aren't powers of two. These are split out so that the slice functions can test for a power of two once, outside the loop
+        impl $struct_name {
+            #[inline(always)]
+            fn div_non_power_of_two(numerator: $primitive_type, denom: Self) -> $primitive_type {
+                let numerator = numerator as u64;
+                let multiplied_hi = numerator * (denom.multiplier >> 32);
+                let multiplied_lo = numerator * (denom.multiplier as u32 as u64) >> 32;
+
+                ((multiplied_hi + multiplied_lo) >> 32) as $primitive_type
+            }
+
+            #[inline(always)]
+            fn rem_non_power_of_two(numerator: $primitive_type, denom: Self) -> $primitive_type {
+                let product = denom.multiplier.wrapping_mul(numerator as u64) as u128;
+                let divisor = denom.divisor as u128;
+
+                let shifted = (product * divisor) >> 64;
+                shifted as $primitive_type
             }
         }
 
@@ -271,11 +1074,7 @@ macro_rules! strength_reduced_u32 {
                 if rhs.multiplier == 0 {
                     self >> rhs.divisor.trailing_zeros()
                 } else {
-                    let numerator = self as u64;
-                    let multiplied_hi = numerator * (rhs.multiplier >> 32);
-                    let multiplied_lo = numerator * (rhs.multiplier as u32 as u64) >> 32;
-
-                    ((multiplied_hi + multiplied_lo) >> 32) as $primitive_type
+                    $struct_name::div_non_power_of_two(self, rhs)
                 }
             }
         }
@@ -288,17 +1087,41 @@ macro_rules! strength_reduced_u32 {
                 if rhs.multiplier == 0 {
                     self & (rhs.divisor - 1)
                 } else {
-                    let product = rhs.multiplier.wrapping_mul(self as u64) as u128;
-                    let divisor = rhs.divisor as u128;
-
-                    let shifted = (product * divisor) >> 64;
-                    shifted as $primitive_type
+                    $struct_name::rem_non_power_of_two(self, rhs)
                 }
             }
         }
     )
 }
 
+// StrengthReducedU64's rounding and division steps, outside of the struct so that StrengthReducedU128 can reuse them for divisors and numerators that fit in 64 bits.
+// The rounding step takes floor(2^(64 + shift) / divisor), and returns the multiplier along with its add_indicator
+#[inline]
+const fn u64_multiplier_from_quotient(divisor: u64, quotient: u64, shift: u32) -> (u64, bool) {
+    // 2^(64 + shift) is a multiple of 2^64, so the remainder is just the lower half of quotient * divisor, negated
+    let remainder = quotient.wrapping_mul(divisor).wrapping_neg();
+
+    // rounding the quotient up adds an error of divisor - remainder
+    if divisor - remainder < 1 << shift {
+        (quotient + 1, false)
+    } else {
+        // floor(2^(65 + shift) / divisor) is twice the quotient, plus one if twice the remainder reaches the divisor. Its 65th bit is always set, so it's dropped here
+        let doubled_quotient = quotient.wrapping_add(quotient) + (remainder >= divisor - remainder) as u64;
+        (doubled_quotient.wrapping_add(1), true)
+    }
+}
+
+#[inline(always)]
+fn u64_divide_with_multiplier(numerator: u64, multiplier: u64, shift: u32, add_indicator: bool) -> u64 {
+    let quotient = ((numerator as u128 * multiplier as u128) >> 64) as u64;
+    if add_indicator {
+        // (numerator + quotient) >> (shift + 1), without overflowing. The quotient is never larger than the numerator
+        (((numerator - quotient) >> 1) + quotient) >> shift
+    } else {
+        quotient >> shift
+    }
+}
+
 macro_rules! strength_reduced_u64 {
     ($struct_name:ident, $primitive_type:ident) => (
         /// Implements unsigned division and modulo via mutiplication and shifts.
@@ -307,8 +1130,12 @@ macro_rules! strength_reduced_u64 {
         /// this version will be several times faster than naive division.
         #[derive(Clone, Copy, Debug)]
         pub struct $struct_name {
-            multiplier: u128,
+            multiplier: u64,
             divisor: $primitive_type,
+            inverse: $primitive_type,
+            max_quotient: $primitive_type,
+            shift: u32,
+            add_indicator: bool,
         }
         impl $struct_name {
             /// Creates a new divisor instance.
@@ -319,34 +1146,125 @@ macro_rules! strength_reduced_u64 {
             /// 
             /// Panics if `divisor` is 0
             #[inline]
-            pub fn new(divisor: $primitive_type) -> Self {
+            pub const fn new(divisor: $primitive_type) -> Self {
                 assert!(divisor > 0);
 
                 if divisor.is_power_of_two() { 
-                    Self{ multiplier: 0, divisor }
+                    Self{ multiplier: 0, divisor, inverse: 1, max_quotient: $primitive_type::MAX >> divisor.trailing_zeros(), shift: divisor.trailing_zeros(), add_indicator: false }
+                } else {
+                    // This is the same scheme as libdivide. The quotient is the upper half of numerator * ceil(2^(64 + shift) / divisor), shifted right by `shift`,
+                    // as long as rounding the multiplier up doesn't add too much error. If it does, we use ceil(2^(65 + shift) / divisor) instead, which is exact but 65 bits wide,
+                    // so we store its lower 64 bits and set add_indicator, which tells div to add the numerator back in to make up for the missing 2^64
+                    let shift = 63 - (divisor as u64).leading_zeros();
+                    let inverse = modular_inverse::modular_inverse_u64((divisor >> divisor.trailing_zeros()) as u64) as $primitive_type;
+
+                    // floor(2^(64 + shift) / divisor)
+                    let quotient = long_division::divide_power_of_two_by_64(divisor as u64);
+                    let max_quotient = (quotient >> shift) as $primitive_type;
+
+                    let (multiplier, add_indicator) = u64_multiplier_from_quotient(divisor as u64, quotient, shift);
+                    Self{ multiplier, divisor, inverse, max_quotient, shift, add_indicator }
+                }
+            }
+
+            // The full multiplier, which includes the 65th bit that add_indicator stands in for
+            #[cfg(feature = "serde")]
+            pub(crate) fn full_multiplier(&self) -> u128 {
+                ((self.add_indicator as u128) << 64) | self.multiplier as u128
+            }
+
+            // Same idea as StrengthReducedU8::from_multiplier, which is much cheaper than the 128-bit long division that new() does. The full multiplier is ceil(2^(64 + shift) / divisor),
+            // or ceil(2^(65 + shift) / divisor) if the first one is too imprecise, so both the value and the choice between them are checked.
+            // 2^(65 + shift) can be as large as 2^128, so products are compared against powers of two by shifting them instead
+            #[cfg(feature = "serde")]
+            pub(crate) fn from_multiplier(divisor: $primitive_type, multiplier: u128) -> Option<Self> {
+                fn below_power_of_two(value: u128, exponent: u32) -> bool {
+                    exponent >= 128 || value >> exponent == 0
+                }
+
+                if divisor == 0 {
+                    None
+                } else if divisor.is_power_of_two() {
+                    if multiplier == 0 { Some(Self::new(divisor)) } else { None }
                 } else {
-                    let quotient = long_division::divide_128_max_by_64(divisor as u64);
-                    Self{ multiplier: quotient + 1, divisor }
+                    let shift = 63 - (divisor as u64).leading_zeros();
+                    let add_indicator = multiplier >> 64 != 0;
+                    let exponent = 64 + shift + add_indicator as u32;
+
+                    let product_too_small = match multiplier.checked_mul(divisor as u128) {
+                        Some(product) => below_power_of_two(product, exponent),
+                        None => false,
+                    };
+                    let previous_too_large = match multiplier.checked_sub(1).and_then(|previous| previous.checked_mul(divisor as u128)) {
+                        Some(product) => !below_power_of_two(product, exponent),
+                        None => true,
+                    };
+                    if product_too_small || previous_too_large {
+                        return None;
+                    }
+
+                    // the multiplier is one of the two candidates. Recover the narrower one, and make sure that new() would have made the same choice
+                    let narrow_multiplier = if add_indicator { (((multiplier - 1) >> 1) + 1) as u64 } else { multiplier as u64 };
+                    if (narrow_multiplier.wrapping_mul(divisor as u64) < 1 << shift) == add_indicator {
+                        return None;
+                    }
+
+                    let inverse = modular_inverse::modular_inverse_u64((divisor >> divisor.trailing_zeros()) as u64) as $primitive_type;
+                    let max_quotient = ((narrow_multiplier - 1) >> shift) as $primitive_type;
+                    Some(Self{ multiplier: multiplier as u64, divisor, inverse, max_quotient, shift, add_indicator })
                 }
             }
+
             /// Simultaneous truncated integer division and modulus.
             /// Returns `(quotient, remainder)`.
             #[inline]
             pub fn div_rem(numerator: $primitive_type, denom: Self) -> ($primitive_type, $primitive_type) {
                 if denom.multiplier == 0 {
-                    (numerator >> denom.divisor.trailing_zeros(), numerator & (denom.divisor - 1))
+                    (numerator >> denom.shift, numerator & (denom.divisor - 1))
                 }
                 else {
-                    let numerator128 = numerator as u128;
-                    let multiplied_hi = numerator128 * (denom.multiplier >> 64);
-                    let multiplied_lo = numerator128 * (denom.multiplier as u64 as u128) >> 64;
-
-                    let quotient = ((multiplied_hi + multiplied_lo) >> 64) as $primitive_type;
+                    let quotient = Self::div_non_power_of_two(numerator, denom);
                     let remainder = numerator - quotient * denom.divisor;
                     (quotient, remainder)
                 }
             }
 
+            /// Returns true if `numerator` is a multiple of the divisor, ie if `numerator % divisor == 0`.
+            ///
+            /// This is faster than computing the remainder: it only takes a multiplication, a rotation, and a comparison.
+            #[inline]
+            pub fn is_multiple_of(numerator: $primitive_type, denom: Self) -> bool {
+                numerator.wrapping_mul(denom.inverse).rotate_right(denom.divisor.trailing_zeros()) <= denom.max_quotient
+            }
+
+            /// Returns true if this divisor divides `numerator` evenly. Equivalent to `is_multiple_of(numerator, divisor)`
+            #[inline]
+            pub fn divides(&self, numerator: $primitive_type) -> bool {
+                Self::is_multiple_of(numerator, *self)
+            }
+
+            /// Divides `numerator` by the divisor, assuming that `numerator` is an exact multiple of the divisor.
+            ///
+            /// This only takes a shift and a multiplication, which is cheaper than a regular division.
+            /// If `numerator` isn't a multiple of the divisor, the returned value is meaningless. Use `checked_divide_exact` when that can't be ruled out.
+            ///
+            /// # Panics:
+            ///
+            /// In debug builds, panics if `numerator` isn't a multiple of the divisor
+            #[inline]
+            pub fn divide_exact(numerator: $primitive_type, denom: Self) -> $primitive_type {
+                debug_assert!(Self::is_multiple_of(numerator, denom), "divide_exact called with a numerator that isn't a multiple of the divisor");
+                (numerator >> denom.divisor.trailing_zeros()).wrapping_mul(denom.inverse)
+            }
+
+            /// Divides `numerator` by the divisor if `numerator` is an exact multiple of the divisor. Returns `None` otherwise.
+            #[inline]
+            pub fn checked_divide_exact(numerator: $primitive_type, denom: Self) -> Option<$primitive_type> {
+                // For multiples of the divisor, the value that is_multiple_of compares against the bound is exactly the quotient
+                let quotient = numerator.wrapping_mul(denom.inverse).rotate_right(denom.divisor.trailing_zeros());
+                if quotient <= denom.max_quotient { Some(quotient) } else { None }
+            }
+
             /// Retrieve the value used to create this struct
             #[inline]
             pub fn get(&self) -> $primitive_type {
@@ -354,6 +1272,22 @@ macro_rules! strength_reduced_u64 {
             }
         }
 
+        // Division and remainder for divisors that aren't powers of two. These are split out so that the slice functions can test for a power of two once, outside the loop
+        impl $struct_name {
+            #[inline(always)]
+            fn div_non_power_of_two(numerator: $primitive_type, denom: Self) -> $primitive_type {
+                u64_divide_with_multiplier(numerator as u64, denom.multiplier, denom.shift, denom.add_indicator) as $primitive_type
+            }
+
+            // Computing the remainder directly, like StrengthReducedU32 does, would take a second multiplier, ceil(2^128 / divisor), stored in another u128 field
+            // and multiplied out with 128x64-bit products. That's slower than multiplying the quotient back, in loops and in rem_slice. repeated_modulo_direct and slice_modulo_direct in the benchmarks compare the two
+            #[inline(always)]
+            fn rem_non_power_of_two(numerator: $primitive_type, denom: Self) -> $primitive_type {
+                let quotient = Self::div_non_power_of_two(numerator, denom);
+                numerator - quotient * denom.divisor
+            }
+        }
+
         impl Div<$struct_name> for $primitive_type {
             type Output = $primitive_type;
 
@@ -362,11 +1296,7 @@ macro_rules! strength_reduced_u64 {
                 if rhs.multiplier == 0 {
                     self >> rhs.divisor.trailing_zeros()
                 } else {
-                    let numerator = self as u128;
-                    let multiplied_hi = numerator * (rhs.multiplier >> 64);
-                    let multiplied_lo = numerator * (rhs.multiplier as u64 as u128) >> 64;
-
-                    ((multiplied_hi + multiplied_lo) >> 64) as $primitive_type
+                    $struct_name::div_non_power_of_two(self, rhs)
                 }
             }
         }
@@ -379,23 +1309,202 @@ macro_rules! strength_reduced_u64 {
                 if rhs.multiplier == 0 {
                     self & (rhs.divisor - 1)
                 } else {
-                    let quotient = self / rhs;
-                    self - quotient * rhs.divisor
+                    $struct_name::rem_non_power_of_two(self, rhs)
+                }
+            }
+        }
+    )
+}
+
+// Division of numerators twice as wide as the divisor.
+// StrengthReducedU32's multiplier is ceil(2^64 / divisor). For a numerator that fits in 64 bits, the upper half of the numerator times the multiplier
+// is an estimate of the quotient that is either exact or one too large, so a single correction step gives the exact quotient.
+impl StrengthReducedU32 {
+    /// Simultaneous truncated division and modulus of a 64-bit numerator.
+    /// Returns `(quotient, remainder)`. The remainder is always smaller than the divisor, so it's returned as a u32.
+    #[inline]
+    pub fn div_rem_wide(numerator: u64, denom: Self) -> (u64, u32) {
+        if denom.multiplier == 0 {
+            (numerator >> denom.divisor.trailing_zeros(), numerator as u32 & (denom.divisor - 1))
+        } else {
+            let estimate = ((numerator as u128 * denom.multiplier as u128) >> 64) as u64;
+            let remainder = numerator.wrapping_sub(estimate.wrapping_mul(denom.divisor as u64));
+
+            // if the estimate was one too large, the remainder wrapped around to something larger than the divisor
+            if remainder >= denom.divisor as u64 {
+                (estimate - 1, remainder.wrapping_add(denom.divisor as u64) as u32)
+            } else {
+                (estimate, remainder as u32)
+            }
+        }
+    }
+
+    /// Divides the 64-bit numerator `(numerator_hi << 32) | numerator_lo` by the divisor, for when the quotient is known to fit in a u32.
+    /// Returns `(quotient, remainder)`.
+    ///
+    /// # Panics:
+    ///
+    /// Panics if `numerator_hi` is greater than or equal to the divisor, because the quotient would not fit in a u32
+    #[inline]
+    pub fn div_rem_narrowing(numerator_hi: u32, numerator_lo: u32, denom: Self) -> (u32, u32) {
+        assert!(numerator_hi < denom.divisor, "attempt to divide with overflow");
+
+        let (quotient, remainder) = Self::div_rem_wide(((numerator_hi as u64) << 32) | numerator_lo as u64, denom);
+        (quotient as u32, remainder)
+    }
+}
+
+// StrengthReducedU64 doesn't store a 128-bit multiplier, so its wide division is built from a regular division of the upper half of the numerator,
+// and a narrowing division of the remainder and the lower half.
+impl StrengthReducedU64 {
+    /// Simultaneous truncated division and modulus of a 128-bit numerator.
+    /// Returns `(quotient, remainder)`. The remainder is always smaller than the divisor, so it's returned as a u64.
+    #[inline]
+    pub fn div_rem_wide(numerator: u128, denom: Self) -> (u128, u64) {
+        if denom.multiplier == 0 {
+            (numerator >> denom.shift, numerator as u64 & (denom.divisor - 1))
+        } else {
+            let (quotient_hi, remainder_hi) = Self::div_rem((numerator >> 64) as u64, denom);
+            let (quotient_lo, remainder) = Self::div_rem_narrowing_non_power_of_two(remainder_hi, numerator as u64, denom);
+            (((quotient_hi as u128) << 64) | quotient_lo as u128, remainder)
+        }
+    }
+
+    /// Divides the 128-bit numerator `(numerator_hi << 64) | numerator_lo` by the divisor, for when the quotient is known to fit in a u64.
+    /// Returns `(quotient, remainder)`.
+    ///
+    /// # Panics:
+    ///
+    /// Panics if `numerator_hi` is greater than or equal to the divisor, because the quotient would not fit in a u64
+    #[inline]
+    pub fn div_rem_narrowing(numerator_hi: u64, numerator_lo: u64, denom: Self) -> (u64, u64) {
+        assert!(numerator_hi < denom.divisor, "attempt to divide with overflow");
+
+        if denom.multiplier == 0 {
+            let numerator = ((numerator_hi as u128) << 64) | numerator_lo as u128;
+            ((numerator >> denom.shift) as u64, numerator_lo & (denom.divisor - 1))
+        } else {
+            Self::div_rem_narrowing_non_power_of_two(numerator_hi, numerator_lo, denom)
+        }
+    }
+
+    // The 2-by-1 division from Möller and Granlund's "Improved division by invariant integers". It needs the divisor shifted so that its top bit is set,
+    // and the reciprocal floor((2^128 - 1) / shifted_divisor) - 2^64, which is the lower half of floor(2^(65 + shift) / divisor). That isn't stored, but it's cheap to recover from the multiplier
+    #[inline(always)]
+    fn div_rem_narrowing_non_power_of_two(numerator_hi: u64, numerator_lo: u64, denom: Self) -> (u64, u64) {
+        let reciprocal = if denom.add_indicator {
+            // the full multiplier is floor(2^(65 + shift) / divisor) + 1
+            denom.multiplier - 1
+        } else {
+            // the multiplier is floor(2^(64 + shift) / divisor) + 1. Multiplying it back out gives the remainder of that division, which decides the bit that doubling it shifts in
+            let quotient = denom.multiplier - 1;
+            let remainder = denom.divisor - denom.multiplier.wrapping_mul(denom.divisor);
+            quotient.wrapping_add(quotient) + (remainder >= denom.divisor - remainder) as u64
+        };
+
+        // numerator_hi < divisor, so normalizing doesn't shift any bits out of the numerator
+        let normalize_shift = denom.divisor.leading_zeros();
+        let divisor = denom.divisor << normalize_shift;
+        let numerator = (((numerator_hi as u128) << 64) | numerator_lo as u128) << normalize_shift;
+        let numerator_hi = (numerator >> 64) as u64;
+        let numerator_lo = numerator as u64;
+
+        // the upper half of the estimate is a quotient that's at most one too large or one too small, and its lower half tells us which
+        let estimate = (reciprocal as u128 * numerator_hi as u128).wrapping_add(((numerator_hi as u128 + 1) << 64) | numerator_lo as u128);
+        let mut quotient = (estimate >> 64) as u64;
+        let mut remainder = numerator_lo.wrapping_sub(quotient.wrapping_mul(divisor));
+        if remainder > estimate as u64 {
+            quotient = quotient.wrapping_sub(1);
+            remainder = remainder.wrapping_add(divisor);
+        }
+        if remainder >= divisor {
+            quotient += 1;
+            remainder -= divisor;
+        }
+        (quotient, remainder >> normalize_shift)
+    }
+}
+
+// Short division of arbitrary-precision numbers, stored as little-endian slices of limbs, by a single-limb divisor.
+// This is the schoolbook algorithm: working down from the most significant limb, the running remainder is always smaller than the divisor,
+// so each step is a narrowing division of (remainder, limb) whose quotient fits in a single limb.
+macro_rules! impl_limb_division {
+    ($struct_name:ident, $primitive_type:ident) => (
+        impl $struct_name {
+            /// Divides the multi-limb number `numerator` by the divisor, storing the quotient in `quotient` and returning the remainder.
+            ///
+            /// Both slices are little-endian: the least significant limb comes first.
+            ///
+            /// # Panics:
+            ///
+            /// Panics if `numerator` and `quotient` have different lengths
+            #[inline]
+            pub fn div_rem_limbs(numerator: &[$primitive_type], denom: Self, quotient: &mut [$primitive_type]) -> $primitive_type {
+                assert_eq!(numerator.len(), quotient.len(), "numerator and quotient must have the same length");
+
+                let mut remainder = 0;
+                for (quotient_limb, &numerator_limb) in quotient.iter_mut().zip(numerator).rev() {
+                    let (limb_quotient, limb_remainder) = Self::div_rem_limb(remainder, numerator_limb, denom);
+                    *quotient_limb = limb_quotient;
+                    remainder = limb_remainder;
+                }
+                remainder
+            }
+
+            /// Divides the multi-limb number `limbs` by the divisor in place, replacing it with the quotient and returning the remainder.
+            ///
+            /// `limbs` is little-endian: the least significant limb comes first.
+            #[inline]
+            pub fn div_rem_limbs_in_place(limbs: &mut [$primitive_type], denom: Self) -> $primitive_type {
+                let mut remainder = 0;
+                for limb in limbs.iter_mut().rev() {
+                    let (limb_quotient, limb_remainder) = Self::div_rem_limb(remainder, *limb, denom);
+                    *limb = limb_quotient;
+                    remainder = limb_remainder;
+                }
+                remainder
+            }
+
+            /// Computes the remainder of the multi-limb number `numerator` divided by the divisor, without storing the quotient anywhere.
+            ///
+            /// `numerator` is little-endian: the least significant limb comes first.
+            #[inline]
+            pub fn rem_limbs(numerator: &[$primitive_type], denom: Self) -> $primitive_type {
+                numerator.iter().rev().fold(0, |remainder, &numerator_limb| Self::div_rem_limb(remainder, numerator_limb, denom).1)
+            }
+
+            #[inline(always)]
+            fn div_rem_limb(remainder: $primitive_type, numerator_limb: $primitive_type, denom: Self) -> ($primitive_type, $primitive_type) {
+                if remainder == 0 {
+                    // The remainder is zero, which means we can take a shortcut and only do a single-width division!
+                    Self::div_rem(numerator_limb, denom)
+                } else {
+                    Self::div_rem_narrowing(remainder, numerator_limb, denom)
                 }
             }
         }
     )
 }
 
+impl_limb_division!(StrengthReducedU32, u32);
+impl_limb_division!(StrengthReducedU64, u64);
+
 /// Implements unsigned division and modulo via mutiplication and shifts.
 ///
 /// Creating a an instance of this struct is more expensive than a single division, but if the division is repeated,
 /// this version will be several times faster than naive division.
 #[derive(Clone, Copy, Debug)]
 pub struct StrengthReducedU128 {
-    multiplier_hi: u128,
-    multiplier_lo: u128,
+    multiplier: u128,
     divisor: u128,
+    inverse: u128,
+    max_quotient: u128,
+    shift: u32,
+    add_indicator: bool,
+
+    // StrengthReducedU64's multiplier and add_indicator for the same divisor, if it fits in 64 bits
+    multiplier_u64: u64,
+    add_indicator_u64: bool,
 }
 impl StrengthReducedU128 {
     /// Creates a new divisor instance.
@@ -406,16 +1515,71 @@ impl StrengthReducedU128 {
     /// 
     /// Panics if `divisor` is 0
     #[inline]
-    pub fn new(divisor: u128) -> Self {
+    pub const fn new(divisor: u128) -> Self {
         assert!(divisor > 0);
 
         if divisor.is_power_of_two() { 
-            Self{ multiplier_hi: 0, multiplier_lo: 0, divisor }
+            Self{ multiplier: 0, divisor, inverse: 1, max_quotient: u128::MAX >> divisor.trailing_zeros(), shift: divisor.trailing_zeros(), add_indicator: false, multiplier_u64: 0, add_indicator_u64: false }
+        } else {
+            Self::from_quotient(divisor, long_division::divide_power_of_two_by_128(divisor))
+        }
+    }
+
+    // This is the same scheme as StrengthReducedU64, scaled up to 128 bits: the quotient is the upper half of numerator * ceil(2^(128 + shift) / divisor), shifted right by `shift`,
+    // or the 129-bit ceil(2^(129 + shift) / divisor) with its top bit replaced by add_indicator if the first one is too imprecise.
+    // Both are derived from `quotient`, which has to be floor(2^(128 + shift) / divisor), for a divisor that isn't a power of two
+    const fn from_quotient(divisor: u128, quotient: u128) -> Self {
+        let shift = 127 - divisor.leading_zeros();
+        let inverse = modular_inverse::modular_inverse_u128(divisor >> divisor.trailing_zeros());
+
+        // 2^(128 + shift) is a multiple of 2^128, so the remainder is just the lower half of quotient * divisor, negated
+        let remainder = quotient.wrapping_mul(divisor).wrapping_neg();
+        let max_quotient = quotient >> shift;
+
+        // If the divisor fits in 64 bits, the upper half of the quotient is floor(2^(64 + shift) / divisor), which is exactly what StrengthReducedU64 rounds into its multiplier
+        let (multiplier_u64, add_indicator_u64) = if divisor >> 64 == 0 { u64_multiplier_from_quotient(divisor as u64, (quotient >> 64) as u64, shift) } else { (0, false) };
+
+        // rounding the quotient up adds an error of divisor - remainder
+        if divisor - remainder < 1 << shift {
+            Self{ multiplier: quotient + 1, divisor, inverse, max_quotient, shift, add_indicator: false, multiplier_u64, add_indicator_u64 }
+        } else {
+            let doubled_quotient = quotient.wrapping_add(quotient) + (remainder >= divisor - remainder) as u128;
+            Self{ multiplier: doubled_quotient.wrapping_add(1), divisor, inverse, max_quotient, shift, add_indicator: true, multiplier_u64, add_indicator_u64 }
+        }
+    }
+
+    // The full 129-bit multiplier, as its (upper, lower) halves
+    #[cfg(feature = "serde")]
+    pub(crate) fn full_multiplier(&self) -> (u128, u128) {
+        (self.add_indicator as u128, self.multiplier)
+    }
+
+    // Rebuilds a divisor from a multiplier that was computed elsewhere. Instead of checking the multiplier's products against a power of two, which would need 257-bit arithmetic,
+    // this undoes the rounding in from_quotient to get back the quotient that new() would have started from, checks that quotient with a single multiplication,
+    // and then makes sure from_quotient rounds it back to the same multiplier
+    #[cfg(feature = "serde")]
+    pub(crate) fn from_multiplier(divisor: u128, (multiplier_hi, multiplier_lo): (u128, u128)) -> Option<Self> {
+        if divisor == 0 {
+            None
+        } else if divisor.is_power_of_two() {
+            if multiplier_hi == 0 && multiplier_lo == 0 { Some(Self::new(divisor)) } else { None }
+        } else if multiplier_hi > 1 {
+            None
         } else {
-            let (quotient_hi, quotient_lo) = long_division::divide_256_max_by_128(divisor);
-            let multiplier_lo = quotient_lo.wrapping_add(1);
-            let multiplier_hi = if multiplier_lo == 0 { quotient_hi + 1 } else { quotient_hi };
-            Self{ multiplier_hi, multiplier_lo, divisor }
+            // subtract 1 from the multiplier, and if it's the wide one, halve it. The 129th bit of the wide multiplier becomes the 128th bit of the quotient, unless subtracting 1 borrowed from it
+            let previous = multiplier_lo.wrapping_sub(1);
+            let quotient = if multiplier_hi == 0 { previous } else { (previous >> 1) | (((multiplier_lo != 0) as u128) << 127) };
+
+            // quotient * divisor has to be 2^(128 + shift) minus a remainder smaller than the divisor
+            let shift = 127 - divisor.leading_zeros();
+            let product_hi = long_multiplication::multiply_128_by_128_upperbits(quotient, divisor);
+            let product_lo = quotient.wrapping_mul(divisor);
+            if product_hi.wrapping_add((product_lo != 0) as u128) != 1 << shift || product_lo.wrapping_neg() >= divisor {
+                return None;
+            }
+
+            let reduced = Self::from_quotient(divisor, quotient);
+            if reduced.full_multiplier() == (multiplier_hi, multiplier_lo) { Some(reduced) } else { None }
         }
     }
 
@@ -428,6 +1592,42 @@ impl StrengthReducedU128 {
         (quotient, remainder)
     }
 
+    /// Returns true if `numerator` is a multiple of the divisor, ie if `numerator % divisor == 0`.
+    ///
+    /// This is faster than computing the remainder: it only takes a multiplication, a rotation, and a comparison.
+    #[inline]
+    pub fn is_multiple_of(numerator: u128, denom: Self) -> bool {
+        numerator.wrapping_mul(denom.inverse).rotate_right(denom.divisor.trailing_zeros()) <= denom.max_quotient
+    }
+
+    /// Returns true if this divisor divides `numerator` evenly. Equivalent to `StrengthReducedU128::is_multiple_of(numerator, divisor)`
+    #[inline]
+    pub fn divides(&self, numerator: u128) -> bool {
+        Self::is_multiple_of(numerator, *self)
+    }
+
+    /// Divides `numerator` by the divisor, assuming that `numerator` is an exact multiple of the divisor.
+    ///
+    /// This only takes a shift and a multiplication, which is cheaper than a regular division.
+    /// If `numerator` isn't a multiple of the divisor, the returned value is meaningless. Use `checked_divide_exact` when that can't be ruled out.
+    ///
+    /// # Panics:
+    ///
+    /// In debug builds, panics if `numerator` isn't a multiple of the divisor
+    #[inline]
+    pub fn divide_exact(numerator: u128, denom: Self) -> u128 {
+        debug_assert!(Self::is_multiple_of(numerator, denom), "divide_exact called with a numerator that isn't a multiple of the divisor");
+        (numerator >> denom.divisor.trailing_zeros()).wrapping_mul(denom.inverse)
+    }
+
+    /// Divides `numerator` by the divisor if `numerator` is an exact multiple of the divisor. Returns `None` otherwise.
+    #[inline]
+    pub fn checked_divide_exact(numerator: u128, denom: Self) -> Option<u128> {
+        // For multiples of the divisor, the value that is_multiple_of compares against the bound is exactly the quotient
+        let quotient = numerator.wrapping_mul(denom.inverse).rotate_right(denom.divisor.trailing_zeros());
+        if quotient <= denom.max_quotient { Some(quotient) } else { None }
+    }
+
     /// Retrieve the value used to create this struct
     #[inline]
     pub fn get(&self) -> u128 {
@@ -435,15 +1635,59 @@ impl StrengthReducedU128 {
     }
 }
 
+// Division and remainder for divisors that aren't powers of two. These are split out so that the slice functions can test for a power of two once, outside the loop
+impl StrengthReducedU128 {
+    // Numerators that fit in 64 bits never need the 128-bit multiplication. If the divisor fits in 64 bits too, we divide them the same way StrengthReducedU64 would,
+    // and if only the numerator fits, the divisor is larger than the numerator, so the quotient is 0. Numerators that don't fit only pay two comparisons for these shortcuts
+    #[inline(always)]
+    fn fits_u64(numerator: u128, denom: Self) -> bool {
+        (numerator | denom.divisor) >> 64 == 0
+    }
+
+    #[inline(always)]
+    fn div_u64(numerator: u64, denom: Self) -> u64 {
+        u64_divide_with_multiplier(numerator, denom.multiplier_u64, denom.shift, denom.add_indicator_u64)
+    }
+
+    #[inline(always)]
+    fn div_non_power_of_two(numerator: u128, denom: Self) -> u128 {
+        if Self::fits_u64(numerator, denom) {
+            return Self::div_u64(numerator as u64, denom) as u128;
+        }
+        if numerator >> 64 == 0 {
+            return 0;
+        }
+
+        let quotient = long_multiplication::multiply_128_by_128_upperbits(numerator, denom.multiplier);
+        if denom.add_indicator {
+            // (numerator + quotient) >> (shift + 1), without overflowing. The quotient is never larger than the numerator
+            (((numerator - quotient) >> 1) + quotient) >> denom.shift
+        } else {
+            quotient >> denom.shift
+        }
+    }
+
+    #[inline(always)]
+    fn rem_non_power_of_two(numerator: u128, denom: Self) -> u128 {
+        if Self::fits_u64(numerator, denom) {
+            let numerator = numerator as u64;
+            return (numerator - Self::div_u64(numerator, denom) * denom.divisor as u64) as u128;
+        }
+
+        let quotient = Self::div_non_power_of_two(numerator, denom);
+        numerator - quotient * denom.divisor
+    }
+}
+
 impl Div<StrengthReducedU128> for u128 {
     type Output = u128;
 
     #[inline]
     fn div(self, rhs: StrengthReducedU128) -> Self::Output {
-        if rhs.multiplier_hi == 0 {
+        if rhs.multiplier == 0 {
             self >> rhs.divisor.trailing_zeros()
         } else {
-            long_multiplication::multiply_256_by_128_upperbits(rhs.multiplier_hi, rhs.multiplier_lo, self)
+            StrengthReducedU128::div_non_power_of_two(self, rhs)
         }
     }
 }
@@ -453,11 +1697,10 @@ impl Rem<StrengthReducedU128> for u128 {
 
     #[inline]
     fn rem(self, rhs: StrengthReducedU128) -> Self::Output {
-        if rhs.multiplier_hi == 0 {
+        if rhs.multiplier == 0 {
             self & (rhs.divisor - 1)
         } else {
-             let quotient = long_multiplication::multiply_256_by_128_upperbits(rhs.multiplier_hi, rhs.multiplier_lo, self);
-             self - quotient * rhs.divisor
+            StrengthReducedU128::rem_non_power_of_two(self, rhs)
         }
     }
 }
@@ -475,6 +1718,71 @@ strength_reduced_u32!(StrengthReducedUsize, usize);
 #[cfg(target_pointer_width = "64")]
 strength_reduced_u64!(StrengthReducedUsize, usize);
 
+impl_unsigned_division_modes!(StrengthReducedU8, u8);
+impl_unsigned_division_modes!(StrengthReducedU16, u16);
+impl_unsigned_division_modes!(StrengthReducedU32, u32);
+impl_unsigned_division_modes!(StrengthReducedU64, u64);
+impl_unsigned_division_modes!(StrengthReducedU128, u128);
+impl_unsigned_division_modes!(StrengthReducedUsize, usize);
+impl_unsigned_division_modes!(StrengthReducedBranchfreeU32, u32);
+impl_unsigned_division_modes!(StrengthReducedBranchfreeU64, u64);
+
+impl_no_simd_slice_kernel!(StrengthReducedU8, u8);
+#[cfg(not(all(feature = "simd", target_arch = "x86_64")))]
+impl_no_simd_slice_kernel!(StrengthReducedU16, u16);
+#[cfg(not(all(feature = "simd", target_arch = "x86_64")))]
+impl_no_simd_slice_kernel!(StrengthReducedU32, u32);
+#[cfg(not(all(feature = "simd", target_arch = "x86_64")))]
+impl_no_simd_slice_kernel!(StrengthReducedU64, u64);
+impl_no_simd_slice_kernel!(StrengthReducedU128, u128);
+impl_no_simd_slice_kernel!(StrengthReducedUsize, usize);
+
+impl_unsigned_slice_ops!(StrengthReducedU8, u8);
+impl_unsigned_slice_ops!(StrengthReducedU16, u16);
+impl_unsigned_slice_ops!(StrengthReducedU32, u32);
+impl_unsigned_slice_ops!(StrengthReducedU64, u64);
+impl_unsigned_slice_ops!(StrengthReducedU128, u128);
+impl_unsigned_slice_ops!(StrengthReducedUsize, usize);
+
+impl_strength_reduced!(StrengthReducedU8, u8, NonZeroU8);
+impl_strength_reduced!(StrengthReducedU16, u16, NonZeroU16);
+impl_strength_reduced!(StrengthReducedU32, u32, NonZeroU32);
+impl_strength_reduced!(StrengthReducedU64, u64, NonZeroU64);
+impl_strength_reduced!(StrengthReducedU128, u128, NonZeroU128);
+impl_strength_reduced!(StrengthReducedUsize, usize, NonZeroUsize);
+impl_strength_reduced!(StrengthReducedI8, i8, NonZeroI8);
+impl_strength_reduced!(StrengthReducedI16, i16, NonZeroI16);
+impl_strength_reduced!(StrengthReducedI32, i32, NonZeroI32);
+impl_strength_reduced!(StrengthReducedI64, i64, NonZeroI64);
+impl_strength_reduced!(StrengthReducedI128, i128, NonZeroI128);
+impl_strength_reduced!(StrengthReducedIsize, isize, NonZeroIsize);
+impl_strength_reduced!(StrengthReducedBranchfreeU32, u32, NonZeroU32);
+impl_strength_reduced!(StrengthReducedBranchfreeU64, u64, NonZeroU64);
+
+impl_operator_forms!(StrengthReducedU8, u8);
+impl_operator_forms!(StrengthReducedU16, u16);
+impl_operator_forms!(StrengthReducedU32, u32);
+impl_operator_forms!(StrengthReducedU64, u64);
+impl_operator_forms!(StrengthReducedU128, u128);
+impl_operator_forms!(StrengthReducedUsize, usize);
+impl_operator_forms!(StrengthReducedI8, i8);
+impl_operator_forms!(StrengthReducedI16, i16);
+impl_operator_forms!(StrengthReducedI32, i32);
+impl_operator_forms!(StrengthReducedI64, i64);
+impl_operator_forms!(StrengthReducedI128, i128);
+impl_operator_forms!(StrengthReducedIsize, isize);
+impl_operator_forms!(StrengthReducedBranchfreeU32, u32);
+impl_operator_forms!(StrengthReducedBranchfreeU64, u64);
+
+impl_unsigned_numerator_types!(StrengthReducedU8, u8, NonZeroU8);
+impl_unsigned_numerator_types!(StrengthReducedU16, u16, NonZeroU16);
+impl_unsigned_numerator_types!(StrengthReducedU32, u32, NonZeroU32);
+impl_unsigned_numerator_types!(StrengthReducedU64, u64, NonZeroU64);
+impl_unsigned_numerator_types!(StrengthReducedU128, u128, NonZeroU128);
+impl_unsigned_numerator_types!(StrengthReducedUsize, usize, NonZeroUsize);
+impl_unsigned_numerator_types!(StrengthReducedBranchfreeU32, u32, NonZeroU32);
+impl_unsigned_numerator_types!(StrengthReducedBranchfreeU64, u64, NonZeroU64);
+
 #[cfg(test)]
 mod unit_tests {
     use super::*;
@@ -483,7 +1791,7 @@ mod unit_tests {
         ($test_name:ident, $struct_name:ident, $primitive_type:ident) => (
             #[test]
             fn $test_name() {
-                let max = core::$primitive_type::MAX;
+                let max = $primitive_type::MAX;
                 let divisors = [1,2,3,4,5,6,7,8,9,10,11,12,13,14,15,16,17,18,19,20,max-1,max];
                 let numerators = [0,1,2,3,4,5,6,7,8,9,10,11,12,13,14,15,16,17,18,19,20];
 
@@ -504,6 +1812,25 @@ mod unit_tests {
                         assert_eq!(expected_rem, reduced_rem, "Modulo failed with numerator: {}, divisor: {}", numerator, divisor);
                         assert_eq!(expected_div, reduced_combined_div, "div_rem divide failed with numerator: {}, divisor: {}", numerator, divisor);
                         assert_eq!(expected_rem, reduced_combined_rem, "div_rem modulo failed with numerator: {}, divisor: {}", numerator, divisor);
+
+                        let expected_ceil = expected_div + if expected_rem > 0 { 1 } else { 0 };
+                        let expected_round = expected_div + if expected_rem >= divisor - expected_rem { 1 } else { 0 };
+                        assert_eq!(expected_div, $struct_name::div_floor(numerator, reduced_divisor), "div_floor failed with numerator: {}, divisor: {}", numerator, divisor);
+                        assert_eq!(expected_ceil, $struct_name::div_ceil(numerator, reduced_divisor), "div_ceil failed with numerator: {}, divisor: {}", numerator, divisor);
+                        assert_eq!(expected_round, $struct_name::div_round(numerator, reduced_divisor), "div_round failed with numerator: {}, divisor: {}", numerator, divisor);
+                        assert_eq!(numerator.div_euclid(divisor), $struct_name::div_euclid(numerator, reduced_divisor), "div_euclid failed with numerator: {}, divisor: {}", numerator, divisor);
+                        assert_eq!(numerator.rem_euclid(divisor), $struct_name::rem_euclid(numerator, reduced_divisor), "rem_euclid failed with numerator: {}, divisor: {}", numerator, divisor);
+
+                        let reduced_is_multiple = $struct_name::is_multiple_of(numerator, reduced_divisor);
+                        assert_eq!(expected_rem == 0, reduced_is_multiple, "is_multiple_of failed with numerator: {}, divisor: {}", numerator, divisor);
+
+                        let reduced_checked_exact = $struct_name::checked_divide_exact(numerator, reduced_divisor);
+                        if expected_rem == 0 {
+                            assert_eq!(Some(expected_div), reduced_checked_exact, "checked_divide_exact failed with numerator: {}, divisor: {}", numerator, divisor);
+                            assert_eq!(expected_div, $struct_name::divide_exact(numerator, reduced_divisor), "divide_exact failed with numerator: {}, divisor: {}", numerator, divisor);
+                        } else {
+                            assert_eq!(None, reduced_checked_exact, "checked_divide_exact failed with numerator: {}, divisor: {}", numerator, divisor);
+                        }
                     }
                 }
             }
@@ -516,4 +1843,245 @@ mod unit_tests {
     reduction_test!(test_strength_reduced_u64, StrengthReducedU64, u64);
     reduction_test!(test_strength_reduced_usize, StrengthReducedUsize, usize);
     reduction_test!(test_strength_reduced_u128, StrengthReducedU128, u128);
+
+    macro_rules! signed_reduction_test {
+        ($test_name:ident, $struct_name:ident, $primitive_type:ident) => (
+            #[test]
+            fn $test_name() {
+                let min = $primitive_type::MIN;
+                let max = $primitive_type::MAX;
+                let divisors = [1,2,3,4,5,6,7,8,9,10,11,12,13,14,15,16,17,18,19,20,max-1,max,-1,-2,-3,-4,-5,-6,-7,-8,-9,-10,min+1,min];
+                let numerators = [0,1,2,3,4,5,6,7,8,9,10,11,12,13,14,15,16,17,18,19,20,-1,-2,-3,-4,-5,-6,-7,-8,-9,-10,max-1,max,min+1,min];
+
+                for &divisor in &divisors {
+                    let reduced_divisor = $struct_name::new(divisor);
+                    for &numerator in &numerators {
+                        // MIN / -1 overflows, and is covered by its own test
+                        if numerator == min && divisor == -1 {
+                            continue;
+                        }
+
+                        let expected_div = numerator / divisor;
+                        let expected_rem = numerator % divisor;
+
+                        let reduced_div = numerator / reduced_divisor;
+                        assert_eq!(expected_div, reduced_div, "Divide failed with numerator: {}, divisor: {}", numerator, divisor);
+
+                        let reduced_rem = numerator % reduced_divisor;
+                        assert_eq!(expected_rem, reduced_rem, "Modulo failed with numerator: {}, divisor: {}", numerator, divisor);
+
+                        let (reduced_combined_div, reduced_combined_rem) = $struct_name::div_rem(numerator, reduced_divisor);
+                        assert_eq!(expected_div, reduced_combined_div, "div_rem divide failed with numerator: {}, divisor: {}", numerator, divisor);
+                        assert_eq!(expected_rem, reduced_combined_rem, "div_rem modulo failed with numerator: {}, divisor: {}", numerator, divisor);
+
+                        // the exact quotient is negative iff the operands have different signs
+                        let inexact = expected_rem != 0;
+                        let negative = (numerator < 0) != (divisor < 0);
+                        let expected_floor = if inexact && negative { expected_div - 1 } else { expected_div };
+                        let expected_ceil = if inexact && !negative { expected_div + 1 } else { expected_div };
+                        assert_eq!(expected_floor, $struct_name::div_floor(numerator, reduced_divisor), "div_floor failed with numerator: {}, divisor: {}", numerator, divisor);
+                        assert_eq!(expected_ceil, $struct_name::div_ceil(numerator, reduced_divisor), "div_ceil failed with numerator: {}, divisor: {}", numerator, divisor);
+                        assert_eq!(numerator.div_euclid(divisor), $struct_name::div_euclid(numerator, reduced_divisor), "div_euclid failed with numerator: {}, divisor: {}", numerator, divisor);
+                        assert_eq!(numerator.rem_euclid(divisor), $struct_name::rem_euclid(numerator, reduced_divisor), "rem_euclid failed with numerator: {}, divisor: {}", numerator, divisor);
+                    }
+                }
+            }
+        )
+    }
+
+    signed_reduction_test!(test_strength_reduced_i8, StrengthReducedI8, i8);
+    signed_reduction_test!(test_strength_reduced_i16, StrengthReducedI16, i16);
+    signed_reduction_test!(test_strength_reduced_i32, StrengthReducedI32, i32);
+    signed_reduction_test!(test_strength_reduced_i64, StrengthReducedI64, i64);
+    signed_reduction_test!(test_strength_reduced_isize, StrengthReducedIsize, isize);
+    signed_reduction_test!(test_strength_reduced_i128, StrengthReducedI128, i128);
+
+    // Goes through the trait only, to make sure generic code sees the same results as the inherent methods
+    fn generic_div_rem<D: StrengthReduced>(numerator: D::Int, divisor: D::Int) -> (D::Int, D::Int, D::Int, D::Int) {
+        let reduced_divisor = D::new(divisor);
+        let (quotient, remainder) = D::div_rem(numerator, reduced_divisor);
+        (reduced_divisor.get(), numerator / reduced_divisor, quotient, remainder)
+    }
+
+    #[test]
+    fn test_strength_reduced_trait() {
+        assert_eq!(generic_div_rem::<StrengthReducedU8>(200, 7), (7, 28, 28, 4));
+        assert_eq!(generic_div_rem::<StrengthReducedU16>(60000, 7), (7, 8571, 8571, 3));
+        assert_eq!(generic_div_rem::<StrengthReducedU32>(100, 8), (8, 12, 12, 4));
+        assert_eq!(generic_div_rem::<StrengthReducedU64>(1 << 40, 3), (3, (1 << 40) / 3, (1 << 40) / 3, 1));
+        assert_eq!(generic_div_rem::<StrengthReducedUsize>(1000, 33), (33, 30, 30, 10));
+        assert_eq!(generic_div_rem::<StrengthReducedU128>(1 << 100, 3), (3, (1 << 100) / 3, (1 << 100) / 3, 1));
+        assert_eq!(generic_div_rem::<StrengthReducedI8>(-100, 7), (7, -14, -14, -2));
+        assert_eq!(generic_div_rem::<StrengthReducedI16>(30000, -7), (-7, -4285, -4285, 5));
+        assert_eq!(generic_div_rem::<StrengthReducedI32>(-100, -8), (-8, 12, 12, -4));
+        assert_eq!(generic_div_rem::<StrengthReducedI64>(-(1 << 40), 3), (3, -(1 << 40) / 3, -(1 << 40) / 3, -1));
+        assert_eq!(generic_div_rem::<StrengthReducedIsize>(1000, -33), (-33, -30, -30, 10));
+        assert_eq!(generic_div_rem::<StrengthReducedI128>(-(1 << 100), 3), (3, -(1 << 100) / 3, -(1 << 100) / 3, -1));
+    }
+
+    #[test]
+    fn test_strength_reduced_fallible_constructors() {
+        assert_eq!(StrengthReducedU8::try_new(0).unwrap_err(), DivisorError::Zero);
+        assert_eq!(StrengthReducedU64::try_new(0).unwrap_err(), DivisorError::Zero);
+        assert_eq!(StrengthReducedU128::try_new(0).unwrap_err(), DivisorError::Zero);
+        assert_eq!(StrengthReducedI32::try_new(0).unwrap_err(), DivisorError::Zero);
+        assert_eq!(StrengthReducedU16::try_from(0).unwrap_err(), DivisorError::Zero);
+        assert_eq!(StrengthReducedIsize::try_from(0).unwrap_err(), DivisorError::Zero);
+        assert_eq!(<StrengthReducedU32 as StrengthReduced>::try_new(0).unwrap_err(), DivisorError::Zero);
+
+        assert_eq!(StrengthReducedU8::try_new(7).unwrap().get(), 7);
+        assert_eq!(StrengthReducedU32::try_from(7).unwrap().get(), 7);
+        assert_eq!(StrengthReducedI64::try_new(i64::MIN).unwrap().get(), i64::MIN);
+        assert_eq!(StrengthReducedI128::try_from(-7).unwrap().get(), -7);
+        assert_eq!(<StrengthReducedUsize as StrengthReduced>::try_new(7).unwrap().get(), 7);
+
+        assert_eq!(StrengthReducedU64::from(NonZeroU64::new(7).unwrap()).get(), 7);
+        assert_eq!(StrengthReducedU128::from(NonZeroU128::new(u128::MAX).unwrap()).get(), u128::MAX);
+        assert_eq!(StrengthReducedI16::from(NonZeroI16::new(-7).unwrap()).get(), -7);
+        assert_eq!(100 / StrengthReducedU16::from(NonZeroU16::new(7).unwrap()), 14);
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn test_divisor_error_is_std_error() {
+        use std::string::ToString;
+
+        let error: &dyn std::error::Error = &DivisorError::Zero;
+        assert_eq!(error.to_string(), "cannot create a strength-reduced divisor from zero");
+    }
+
+    // if any of these constructors stop being const fn, this will fail to compile
+    const CONST_REDUCED_U8: StrengthReducedU8 = StrengthReducedU8::new(7);
+    const CONST_REDUCED_U16: StrengthReducedU16 = StrengthReducedU16::new(7);
+    const CONST_REDUCED_U32: StrengthReducedU32 = StrengthReducedU32::new(7);
+    const CONST_REDUCED_U64: StrengthReducedU64 = StrengthReducedU64::new(7);
+    const CONST_REDUCED_USIZE: StrengthReducedUsize = StrengthReducedUsize::new(7);
+    const CONST_REDUCED_I32: StrengthReducedI32 = StrengthReducedI32::new(-7);
+    static STATIC_REDUCED_U128: [StrengthReducedU128; 4] = [
+        StrengthReducedU128::new(7),
+        StrengthReducedU128::new(1 << 100),
+        StrengthReducedU128::new(0x1_0000_0000_0000_0003),
+        StrengthReducedU128::new(u128::MAX),
+    ];
+
+    #[test]
+    fn test_strength_reduced_const() {
+        assert_eq!(200 / CONST_REDUCED_U8, 200 / 7);
+        assert_eq!(60000 / CONST_REDUCED_U16, 60000 / 7);
+        assert_eq!(u32::MAX / CONST_REDUCED_U32, u32::MAX / 7);
+        assert_eq!(u64::MAX / CONST_REDUCED_U64, u64::MAX / 7);
+        assert_eq!(usize::MAX / CONST_REDUCED_USIZE, usize::MAX / 7);
+        assert_eq!(i32::MIN / CONST_REDUCED_I32, i32::MIN / -7);
+
+        let numerator = u128::MAX - 12345;
+        for reduced_divisor in &STATIC_REDUCED_U128 {
+            let runtime_divisor = StrengthReducedU128::new(reduced_divisor.get());
+            assert_eq!(numerator / runtime_divisor, numerator / *reduced_divisor);
+            assert_eq!(numerator / reduced_divisor.get(), numerator / *reduced_divisor);
+        }
+    }
+
+    #[test]
+    fn test_strength_reduced_comparisons() {
+        assert_eq!(StrengthReducedU64::new(7), StrengthReducedU64::new(7));
+        assert_ne!(StrengthReducedU64::new(7), StrengthReducedU64::new(8));
+        assert!(StrengthReducedU128::new(u128::MAX - 1) < StrengthReducedU128::new(u128::MAX));
+        assert_eq!(CONST_REDUCED_U32, StrengthReducedU32::new(7));
+
+        let mut divisors = [StrengthReducedI32::new(3), StrengthReducedI32::new(-7), StrengthReducedI32::new(i32::MIN), StrengthReducedI32::new(1)];
+        divisors.sort();
+        assert_eq!(divisors.map(|divisor| divisor.get()), [i32::MIN, -7, 1, 3]);
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn test_strength_reduced_hash() {
+        use std::collections::HashSet;
+
+        let divisors: HashSet<StrengthReducedU32> = [3, 7, 3, 1 << 20, 7].iter().map(|&divisor| StrengthReducedU32::new(divisor)).collect();
+        assert_eq!(divisors.len(), 3);
+        assert!(divisors.contains(&StrengthReducedU32::new(1 << 20)));
+    }
+
+    // these deliberately take references that clippy considers needless, to make sure every operator form exists
+    #[test]
+    #[allow(clippy::op_ref)]
+    fn test_strength_reduced_operator_forms() {
+        let reduced_divisor = StrengthReducedU32::new(7);
+        let values = [0u32, 6, 7, 100, u32::MAX];
+        for value in &values {
+            assert_eq!(value / reduced_divisor, value / 7);
+            assert_eq!(value / &reduced_divisor, value / 7);
+            assert_eq!(*value / &reduced_divisor, value / 7);
+            assert_eq!(value % reduced_divisor, value % 7);
+            assert_eq!(value % &reduced_divisor, value % 7);
+            assert_eq!(*value % &reduced_divisor, value % 7);
+
+            let mut quotient = *value;
+            quotient /= reduced_divisor;
+            assert_eq!(quotient, value / 7);
+            quotient /= &reduced_divisor;
+            assert_eq!(quotient, value / 49);
+
+            let mut remainder = *value;
+            remainder %= reduced_divisor;
+            assert_eq!(remainder, value % 7);
+            remainder %= &StrengthReducedU32::new(4);
+            assert_eq!(remainder, value % 7 % 4);
+
+            let mut wrapping = Wrapping(*value);
+            assert_eq!(wrapping / reduced_divisor, Wrapping(value / 7));
+            assert_eq!(wrapping % reduced_divisor, Wrapping(value % 7));
+            wrapping /= reduced_divisor;
+            wrapping %= reduced_divisor;
+            assert_eq!(wrapping, Wrapping(value / 7 % 7));
+
+            if let Some(nonzero) = NonZeroU32::new(*value) {
+                assert_eq!(nonzero / reduced_divisor, value / 7);
+                assert_eq!(nonzero % reduced_divisor, value % 7);
+            }
+        }
+
+        let reduced_divisor = StrengthReducedI64::new(-3);
+        let mut value = i64::MIN;
+        value /= &reduced_divisor;
+        assert_eq!(value, i64::MIN / -3);
+        value %= reduced_divisor;
+        assert_eq!(value, i64::MIN / -3 % -3);
+        assert_eq!(&-10 / &reduced_divisor, 3);
+        assert_eq!(&-10 % &reduced_divisor, -1);
+    }
+
+    #[test]
+    fn test_strength_reduced_div_round() {
+        // (numerator, divisor, expected) with the ties landing on both sides of zero
+        let cases = [(0, 3, 0), (1, 3, 0), (2, 3, 1), (3, 3, 1), (5, 2, 3), (7, 2, 4), (-5, 2, -3), (5, -2, -3), (-5, -2, 3), (-4, 3, -1), (-5, 3, -2),
+            (i32::MAX, 2, 1 << 30), (i32::MIN, 2, -(1 << 30)), (i32::MIN + 1, i32::MIN, 1), (i32::MAX, i32::MIN, -1), (1 << 29, i32::MIN, 0)];
+        for &(numerator, divisor, expected) in &cases {
+            assert_eq!(expected, StrengthReducedI32::div_round(numerator, StrengthReducedI32::new(divisor)), "div_round failed with numerator: {}, divisor: {}", numerator, divisor);
+        }
+
+        let cases = [(0, 3, 0), (1, 3, 0), (2, 3, 1), (5, 2, 3), (u64::MAX, 2, 1 << 63), (u64::MAX, u64::MAX, 1), (u64::MAX / 2, u64::MAX, 0), (u64::MAX / 2 + 1, u64::MAX, 1)];
+        for &(numerator, divisor, expected) in &cases {
+            assert_eq!(expected, StrengthReducedU64::div_round(numerator, StrengthReducedU64::new(divisor)), "div_round failed with numerator: {}, divisor: {}", numerator, divisor);
+        }
+    }
+
+    #[test]
+    #[should_panic(expected = "attempt to divide with overflow")]
+    fn test_strength_reduced_signed_min_div_negative_one() {
+        let _ = i32::MIN / StrengthReducedI32::new(-1);
+    }
+
+    #[test]
+    #[should_panic(expected = "attempt to calculate the remainder with overflow")]
+    fn test_strength_reduced_signed_min_rem_negative_one() {
+        let _ = i64::MIN % StrengthReducedI64::new(-1);
+    }
+
+    #[test]
+    #[should_panic(expected = "attempt to divide with overflow")]
+    fn test_strength_reduced_div_rem_narrowing_overflow() {
+        let _ = StrengthReducedU64::div_rem_narrowing(7, 0, StrengthReducedU64::new(7));
+    }
 }
@@ -0,0 +1,125 @@
+// Modular arithmetic with a runtime modulus. The modulus is stored as a strength-reduced divisor, so every reduction is a multiplication instead of a division.
+// Products are computed at twice the width of the modulus, and one operand is always reduced first, so the upper half of the product is smaller than the modulus
+// and div_rem_narrowing can reduce it without dividing the upper half on its own.
+
+use ::{StrengthReducedU32, StrengthReducedU64, DivisorError};
+
+macro_rules! modular_arithmetic {
+    ($struct_name:ident, $reduced_type:ident, $primitive_type:ident, $wide_type:ident) => (
+        /// Modular arithmetic with a modulus that's only known at runtime, reducing with a strength-reduced divisor instead of dividing.
+        ///
+        /// Every operation accepts any value as input, not just values that are already smaller than the modulus, and returns a value smaller than the modulus.
+        /// Like the divisor types, creating an instance is more expensive than a single `%`, so it's meant to be created once and reused.
+        #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+        pub struct $struct_name {
+            modulus: $reduced_type,
+        }
+        impl $struct_name {
+            /// Creates a new modular arithmetic context.
+            ///
+            /// # Panics:
+            ///
+            /// Panics if `modulus` is 0
+            #[inline]
+            pub const fn new(modulus: $primitive_type) -> Self {
+                Self{ modulus: $reduced_type::new(modulus) }
+            }
+
+            /// Creates a new modular arithmetic context, returning an error instead of panicking if `modulus` is 0.
+            #[inline]
+            pub const fn try_new(modulus: $primitive_type) -> Result<Self, DivisorError> {
+                match $reduced_type::try_new(modulus) {
+                    Ok(modulus) => Ok(Self{ modulus }),
+                    Err(error) => Err(error),
+                }
+            }
+
+            /// Returns `value % modulus`.
+            #[inline]
+            pub fn reduce(&self, value: $primitive_type) -> $primitive_type {
+                value % self.modulus
+            }
+
+            /// Returns `(a + b) % modulus`, without overflowing.
+            #[inline]
+            pub fn add_mod(&self, a: $primitive_type, b: $primitive_type) -> $primitive_type {
+                // both are smaller than the modulus, so the sum can overflow by at most one modulus
+                let (sum, overflowed) = self.reduce(a).overflowing_add(self.reduce(b));
+                if overflowed || sum >= self.modulus.get() {
+                    sum.wrapping_sub(self.modulus.get())
+                } else {
+                    sum
+                }
+            }
+
+            /// Returns `(a - b) mod modulus`, which is always non-negative.
+            #[inline]
+            pub fn sub_mod(&self, a: $primitive_type, b: $primitive_type) -> $primitive_type {
+                let a = self.reduce(a);
+                let b = self.reduce(b);
+                if a >= b {
+                    a - b
+                } else {
+                    a.wrapping_sub(b).wrapping_add(self.modulus.get())
+                }
+            }
+
+            /// Returns `-a mod modulus`, which is always non-negative.
+            #[inline]
+            pub fn neg_mod(&self, a: $primitive_type) -> $primitive_type {
+                let a = self.reduce(a);
+                if a == 0 {
+                    0
+                } else {
+                    self.modulus.get() - a
+                }
+            }
+
+            /// Returns `(a * b) % modulus`, without overflowing.
+            #[inline]
+            pub fn mul_mod(&self, a: $primitive_type, b: $primitive_type) -> $primitive_type {
+                // chains of multiplications usually pass in a previous result, which is already reduced, so skip the reduction for those
+                let a = if a < self.modulus.get() { a } else { self.reduce(a) };
+                self.mul_reduced(a, b)
+            }
+
+            /// Returns `base.pow(exponent) % modulus`, without overflowing. `pow_mod(base, 0)` is `1 % modulus`.
+            #[inline]
+            pub fn pow_mod(&self, base: $primitive_type, mut exponent: $primitive_type) -> $primitive_type {
+                let mut base = self.reduce(base);
+                let mut result = self.reduce(1);
+                while exponent > 0 {
+                    if exponent & 1 == 1 {
+                        result = self.mul_reduced(result, base);
+                    }
+                    base = self.mul_reduced(base, base);
+                    exponent >>= 1;
+                }
+                result
+            }
+
+            /// Retrieve the modulus used to create this struct
+            #[inline]
+            pub fn modulus(&self) -> $primitive_type {
+                self.modulus.get()
+            }
+
+            /// Retrieve the strength-reduced modulus, for dividing by it directly
+            #[inline]
+            pub fn reduced_modulus(&self) -> $reduced_type {
+                self.modulus
+            }
+
+            // a is smaller than the modulus, so the upper half of the product is too, and the quotient of the narrowing division can't overflow
+            #[inline(always)]
+            fn mul_reduced(&self, a: $primitive_type, b: $primitive_type) -> $primitive_type {
+                let product = a as $wide_type * b as $wide_type;
+                let hi = (product >> $primitive_type::BITS) as $primitive_type;
+                $reduced_type::div_rem_narrowing(hi, product as $primitive_type, self.modulus).1
+            }
+        }
+    )
+}
+
+modular_arithmetic!(ModularU32, StrengthReducedU32, u32, u64);
+modular_arithmetic!(ModularU64, StrengthReducedU64, u64, u128);
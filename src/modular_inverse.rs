@@ -0,0 +1,55 @@
+// Computes the multiplicative inverse of an odd number modulo 2^N, where N is the bit width of the type, using Newton's method.
+// Every odd number is its own inverse modulo 8, so the initial guess is correct in its lowest 3 bits, and each iteration doubles the number of correct bits.
+macro_rules! modular_inverse {
+    ($fn_name:ident, $primitive_type:ident) => (
+        #[inline]
+        pub(crate) const fn $fn_name(value: $primitive_type) -> $primitive_type {
+            debug_assert!(value & 1 == 1, "only odd numbers have an inverse modulo a power of two");
+
+            let mut inverse = value;
+            let mut correct_bits = 3;
+            while correct_bits < core::mem::size_of::<$primitive_type>() * 8 {
+                inverse = inverse.wrapping_mul((2 as $primitive_type).wrapping_sub(value.wrapping_mul(inverse)));
+                correct_bits *= 2;
+            }
+            inverse
+        }
+    )
+}
+
+modular_inverse!(modular_inverse_u8, u8);
+modular_inverse!(modular_inverse_u16, u16);
+modular_inverse!(modular_inverse_u32, u32);
+modular_inverse!(modular_inverse_u64, u64);
+
+// The lower 64 bits of the inverse only depend on the lower 64 bits of the value, so we can get them from the cheaper 64-bit multiplications,
+// and then a single 128-bit iteration doubles them to the full 128 bits
+#[inline]
+pub(crate) const fn modular_inverse_u128(value: u128) -> u128 {
+    let inverse = modular_inverse_u64(value as u64) as u128;
+    inverse.wrapping_mul(2u128.wrapping_sub(value.wrapping_mul(inverse)))
+}
+
+#[cfg(test)]
+mod unit_tests {
+    macro_rules! modular_inverse_test {
+        ($test_name:ident, $fn_name:ident, $primitive_type:ident) => (
+            #[test]
+            fn $test_name() {
+                let max = $primitive_type::MAX;
+                let values = [1, 3, 5, 7, 9, 11, 13, 15, 17, 19, 21, 255, max / 3, max / 2 + 2, max - 2, max];
+
+                for &value in &values {
+                    let inverse = super::$fn_name(value);
+                    assert_eq!(value.wrapping_mul(inverse), 1, "Wrong inverse for {}", value);
+                }
+            }
+        )
+    }
+
+    modular_inverse_test!(test_modular_inverse_u8, modular_inverse_u8, u8);
+    modular_inverse_test!(test_modular_inverse_u16, modular_inverse_u16, u16);
+    modular_inverse_test!(test_modular_inverse_u32, modular_inverse_u32, u32);
+    modular_inverse_test!(test_modular_inverse_u64, modular_inverse_u64, u64);
+    modular_inverse_test!(test_modular_inverse_u128, modular_inverse_u128, u128);
+}
@@ -0,0 +1,141 @@
+// Lane-wise division and remainder of `core::simd` vectors by a strength-reduced divisor.
+// The divisor's constants are broadcast to every lane, so these compute exactly what the scalar operators compute for each lane,
+// and the power-of-two test is made once per vector rather than once per lane.
+
+use core::ops::{Div, Rem};
+use core::simd::Simd;
+use core::simd::num::SimdUint;
+
+use ::{StrengthReducedU8, StrengthReducedU16, StrengthReducedU32, StrengthReducedU64};
+
+// For u8 and u16, the full product of the numerator and the multiplier fits in a lane twice as wide as the multiplier,
+// and dropping the lower half of the product all at once gives the same result as the scalar code's two steps
+#[inline]
+fn divide_u8<const N: usize>(numerator: Simd<u8, N>, denom: StrengthReducedU8) -> Simd<u8, N> {
+    let product = numerator.cast::<u32>() * Simd::splat(denom.multiplier as u32);
+    (product >> Simd::splat(16)).cast()
+}
+
+#[inline]
+fn divide_u16<const N: usize>(numerator: Simd<u16, N>, denom: StrengthReducedU16) -> Simd<u16, N> {
+    let product = numerator.cast::<u64>() * Simd::splat(denom.multiplier as u64);
+    (product >> Simd::splat(32)).cast()
+}
+
+#[inline]
+fn divide_u32<const N: usize>(numerator: Simd<u32, N>, denom: StrengthReducedU32) -> Simd<u32, N> {
+    let numerator = numerator.cast::<u64>();
+    let multiplied_hi = numerator * Simd::splat(denom.multiplier >> 32);
+    let multiplied_lo = (numerator * Simd::splat(denom.multiplier as u32 as u64)) >> Simd::splat(32);
+
+    ((multiplied_hi + multiplied_lo) >> Simd::splat(32)).cast()
+}
+
+// There are no 128-bit lanes, so the 64x64 products are assembled from 32-bit halves. Returns the (upper, lower) 64 bits of each lane of `a` times `b`.
+#[inline]
+fn multiply_wide_u64<const N: usize>(a: Simd<u64, N>, b: u64) -> (Simd<u64, N>, Simd<u64, N>) {
+    let lower_mask = Simd::splat(0xFFFFFFFF);
+    let a_lo = a & lower_mask;
+    let a_hi = a >> Simd::splat(32);
+    let b_lo = Simd::splat(b & 0xFFFFFFFF);
+    let b_hi = Simd::splat(b >> 32);
+
+    let product_lo_lo = a_lo * b_lo;
+    let product_lo_hi = a_lo * b_hi;
+    let product_hi_lo = a_hi * b_lo;
+    let product_hi_hi = a_hi * b_hi;
+
+    let middle = (product_lo_lo >> Simd::splat(32)) + (product_lo_hi & lower_mask) + (product_hi_lo & lower_mask);
+    let upper = product_hi_hi + (product_lo_hi >> Simd::splat(32)) + (product_hi_lo >> Simd::splat(32)) + (middle >> Simd::splat(32));
+    let lower = (middle << Simd::splat(32)) | (product_lo_lo & lower_mask);
+    (upper, lower)
+}
+
+// Same steps as the scalar code: multiply-high, then either a plain shift or, for divisors whose multiplier needed a 65th bit, the overflow-free add before the shift
+#[inline]
+fn divide_u64<const N: usize>(numerator: Simd<u64, N>, denom: StrengthReducedU64) -> Simd<u64, N> {
+    let (quotient, _) = multiply_wide_u64(numerator, denom.multiplier);
+    let shift = Simd::splat(denom.shift as u64);
+
+    if denom.add_indicator {
+        (((numerator - quotient) >> Simd::splat(1)) + quotient) >> shift
+    } else {
+        quotient >> shift
+    }
+}
+
+macro_rules! impl_simd_division {
+    ($struct_name:ident, $primitive_type:ident, $divide_fn:ident) => (
+        impl<const N: usize> Div<$struct_name> for Simd<$primitive_type, N> {
+            type Output = Self;
+
+            // dividing by a power of two really is a shift
+            #[inline]
+            #[allow(clippy::suspicious_arithmetic_impl)]
+            fn div(self, rhs: $struct_name) -> Self::Output {
+                if rhs.divisor.is_power_of_two() {
+                    self >> Simd::splat(rhs.divisor.trailing_zeros() as $primitive_type)
+                } else {
+                    $divide_fn(self, rhs)
+                }
+            }
+        }
+
+        impl<const N: usize> Rem<$struct_name> for Simd<$primitive_type, N> {
+            type Output = Self;
+
+            #[inline]
+            fn rem(self, rhs: $struct_name) -> Self::Output {
+                if rhs.divisor.is_power_of_two() {
+                    self & Simd::splat(rhs.divisor - 1)
+                } else {
+                    // the product is at most the numerator, so the wrapping lane arithmetic never actually wraps
+                    self - $divide_fn(self, rhs) * Simd::splat(rhs.divisor)
+                }
+            }
+        }
+    )
+}
+
+impl_simd_division!(StrengthReducedU8, u8, divide_u8);
+impl_simd_division!(StrengthReducedU16, u16, divide_u16);
+impl_simd_division!(StrengthReducedU32, u32, divide_u32);
+impl_simd_division!(StrengthReducedU64, u64, divide_u64);
+
+#[cfg(test)]
+mod unit_tests {
+    use super::*;
+
+    macro_rules! simd_division_test {
+        ($test_name:ident, $struct_name:ident, $primitive_type:ident) => (
+            #[test]
+            fn $test_name() {
+                let max = $primitive_type::MAX;
+                let divisors = [1, 2, 3, 5, 6, 7, 8, 10, 11, 64, max / 3, max / 2, max / 2 + 1, max - 1, max];
+                let numerators: [$primitive_type; 8] = [0, 1, 2, 100, max / 3, max / 2, max - 1, max];
+
+                for &divisor in &divisors {
+                    let reduced_divisor = $struct_name::new(divisor);
+
+                    let vector = Simd::from_array(numerators);
+                    let quotients = (vector / reduced_divisor).to_array();
+                    let remainders = (vector % reduced_divisor).to_array();
+                    for i in 0..numerators.len() {
+                        assert_eq!(numerators[i] / divisor, quotients[i], "Divide failed with numerator: {}, divisor: {}", numerators[i], divisor);
+                        assert_eq!(numerators[i] % divisor, remainders[i], "Modulo failed with numerator: {}, divisor: {}", numerators[i], divisor);
+                    }
+
+                    // a lane count that doesn't match any hardware vector width
+                    let vector = Simd::from_array([max, max / 5, 7]);
+                    assert_eq!([max / divisor, max / 5 / divisor, 7 / divisor], (vector / reduced_divisor).to_array());
+                    assert_eq!([max % divisor, max / 5 % divisor, 7 % divisor], (vector % reduced_divisor).to_array());
+                }
+            }
+        )
+    }
+
+    simd_division_test!(test_simd_division_u8, StrengthReducedU8, u8);
+    simd_division_test!(test_simd_division_u16, StrengthReducedU16, u16);
+    simd_division_test!(test_simd_division_u32, StrengthReducedU32, u32);
+    simd_division_test!(test_simd_division_u64, StrengthReducedU64, u64);
+}
@@ -0,0 +1,182 @@
+// Integration with num-traits: a trait that maps each primitive integer type to its strength-reduced divisor type,
+// so that generic code bounded on num-traits' PrimInt can create and use reduced divisors without naming a concrete width.
+
+use num_traits::PrimInt;
+
+use ::{StrengthReduced, DivisorError};
+use ::{StrengthReducedU8, StrengthReducedU16, StrengthReducedU32, StrengthReducedU64, StrengthReducedU128, StrengthReducedUsize};
+use ::{StrengthReducedI8, StrengthReducedI16, StrengthReducedI32, StrengthReducedI64, StrengthReducedI128, StrengthReducedIsize};
+
+/// Maps a primitive integer type to its strength-reduced divisor type, and provides checked and Euclidean division against that divisor.
+///
+/// This is implemented for every primitive integer type, so generic code can be written once for all widths.
+///
+/// # Example:
+/// ```
+/// extern crate num_traits;
+/// extern crate strength_reduce;
+///
+/// use num_traits::PrimInt;
+/// use strength_reduce::Reducible;
+///
+/// // Counts how many of `values` land in each of `bucket_count` buckets of size `bucket_size`
+/// fn histogram<T: PrimInt + Reducible>(values: &[T], bucket_size: T, bucket_count: usize) -> Vec<usize> {
+///     let reduced_size = bucket_size.reduced().expect("bucket size can't be zero");
+///     let mut counts = vec![0; bucket_count];
+///     for &value in values {
+///         let bucket = value.div_euclid_reduced(reduced_size).to_usize().unwrap();
+///         counts[bucket.min(bucket_count - 1)] += 1;
+///     }
+///     counts
+/// }
+///
+/// assert_eq!(histogram(&[1u8, 5, 12, 200], 10, 4), vec![2, 1, 0, 1]);
+/// assert_eq!(histogram(&[0i64, 19, 20, 35], 10, 4), vec![1, 1, 1, 1]);
+/// ```
+pub trait Reducible: PrimInt {
+    /// The strength-reduced divisor type for this integer type, e.g. `StrengthReducedU32` for `u32`.
+    type Reduced: StrengthReduced<Int = Self>;
+
+    /// Creates a strength-reduced divisor from this value, or returns `None` if it's zero.
+    #[inline]
+    fn reduced(self) -> Option<Self::Reduced> {
+        Self::try_reduced(self).ok()
+    }
+
+    /// Creates a strength-reduced divisor from this value, returning an error if it can't be used.
+    #[inline]
+    fn try_reduced(self) -> Result<Self::Reduced, DivisorError> {
+        Self::Reduced::try_new(self)
+    }
+
+    /// Checked division by a strength-reduced divisor. Returns `None` if the quotient overflows, which only happens for `MIN / -1` with signed types.
+    #[inline]
+    fn checked_div_reduced(self, denom: Self::Reduced) -> Option<Self> {
+        if quotient_overflows(self, denom) { None } else { Some(Self::Reduced::div_rem(self, denom).0) }
+    }
+
+    /// Checked remainder by a strength-reduced divisor. Like the primitive `checked_rem`, returns `None` for `MIN % -1` with signed types.
+    #[inline]
+    fn checked_rem_reduced(self, denom: Self::Reduced) -> Option<Self> {
+        if quotient_overflows(self, denom) { None } else { Some(Self::Reduced::div_rem(self, denom).1) }
+    }
+
+    /// Euclidean division by a strength-reduced divisor.
+    ///
+    /// # Panics:
+    ///
+    /// Panics for `MIN / -1` with signed types, because the quotient overflows
+    #[inline]
+    fn div_euclid_reduced(self, denom: Self::Reduced) -> Self {
+        Self::Reduced::div_euclid(self, denom)
+    }
+
+    /// Euclidean remainder by a strength-reduced divisor, which is always non-negative.
+    ///
+    /// # Panics:
+    ///
+    /// Panics for `MIN % -1` with signed types, to match the primitive `rem_euclid`
+    #[inline]
+    fn rem_euclid_reduced(self, denom: Self::Reduced) -> Self {
+        Self::Reduced::rem_euclid(self, denom)
+    }
+
+    /// Checked Euclidean division by a strength-reduced divisor. Returns `None` if the quotient overflows.
+    #[inline]
+    fn checked_div_euclid_reduced(self, denom: Self::Reduced) -> Option<Self> {
+        if quotient_overflows(self, denom) { None } else { Some(Self::Reduced::div_euclid(self, denom)) }
+    }
+
+    /// Checked Euclidean remainder by a strength-reduced divisor. Returns `None` for `MIN % -1` with signed types.
+    #[inline]
+    fn checked_rem_euclid_reduced(self, denom: Self::Reduced) -> Option<Self> {
+        if quotient_overflows(self, denom) { None } else { Some(Self::Reduced::rem_euclid(self, denom)) }
+    }
+}
+
+// The only division that overflows is MIN / -1 for signed types. Unsigned types have a MIN of zero, and !0 is MAX rather than -1, so this is always false for them
+#[inline]
+fn quotient_overflows<T: Reducible>(numerator: T, denom: T::Reduced) -> bool {
+    T::min_value() < T::zero() && numerator == T::min_value() && denom.get() == !T::zero()
+}
+
+macro_rules! impl_reducible {
+    ($primitive_type:ident, $struct_name:ident) => (
+        impl Reducible for $primitive_type {
+            type Reduced = $struct_name;
+        }
+    )
+}
+
+impl_reducible!(u8, StrengthReducedU8);
+impl_reducible!(u16, StrengthReducedU16);
+impl_reducible!(u32, StrengthReducedU32);
+impl_reducible!(u64, StrengthReducedU64);
+impl_reducible!(u128, StrengthReducedU128);
+impl_reducible!(usize, StrengthReducedUsize);
+impl_reducible!(i8, StrengthReducedI8);
+impl_reducible!(i16, StrengthReducedI16);
+impl_reducible!(i32, StrengthReducedI32);
+impl_reducible!(i64, StrengthReducedI64);
+impl_reducible!(i128, StrengthReducedI128);
+impl_reducible!(isize, StrengthReducedIsize);
+
+#[cfg(test)]
+mod unit_tests {
+    use super::*;
+
+    fn checked_results<T: Reducible>(numerator: T, divisor: T) -> (Option<T>, Option<T>, Option<T>, Option<T>) {
+        let reduced_divisor = divisor.reduced().unwrap();
+        (
+            numerator.checked_div_reduced(reduced_divisor),
+            numerator.checked_rem_reduced(reduced_divisor),
+            numerator.checked_div_euclid_reduced(reduced_divisor),
+            numerator.checked_rem_euclid_reduced(reduced_divisor),
+        )
+    }
+
+    macro_rules! reducible_test {
+        ($test_name:ident, $primitive_type:ident) => (
+            #[test]
+            fn $test_name() {
+                assert!((0 as $primitive_type).reduced().is_none());
+                assert_eq!((0 as $primitive_type).try_reduced().unwrap_err(), DivisorError::Zero);
+
+                let max = $primitive_type::MAX;
+                let min = $primitive_type::MIN;
+                let values = [min, min + 1, 0, 1, 2, 3, 7, 100, max / 2, max - 1, max];
+
+                for &divisor in &values {
+                    if divisor == 0 {
+                        continue;
+                    }
+                    for &numerator in &values {
+                        let expected = (numerator.checked_div(divisor), numerator.checked_rem(divisor), numerator.checked_div_euclid(divisor), numerator.checked_rem_euclid(divisor));
+                        assert_eq!(expected, checked_results(numerator, divisor), "Checked division failed with numerator: {}, divisor: {}", numerator, divisor);
+                    }
+                }
+            }
+        )
+    }
+
+    reducible_test!(test_reducible_u8, u8);
+    reducible_test!(test_reducible_u16, u16);
+    reducible_test!(test_reducible_u32, u32);
+    reducible_test!(test_reducible_u64, u64);
+    reducible_test!(test_reducible_u128, u128);
+    reducible_test!(test_reducible_usize, usize);
+    reducible_test!(test_reducible_i8, i8);
+    reducible_test!(test_reducible_i16, i16);
+    reducible_test!(test_reducible_i32, i32);
+    reducible_test!(test_reducible_i64, i64);
+    reducible_test!(test_reducible_i128, i128);
+    reducible_test!(test_reducible_isize, isize);
+
+    #[test]
+    fn test_reducible_signed_overflow() {
+        let negative_one = (-1i32).reduced().unwrap();
+        assert_eq!(None, i32::MIN.checked_div_reduced(negative_one));
+        assert_eq!(None, i32::MIN.checked_rem_reduced(negative_one));
+        assert_eq!(Some(i32::MAX.wrapping_neg()), i32::MAX.checked_div_reduced(negative_one));
+    }
+}
@@ -0,0 +1,184 @@
+// Serde support. By default, a divisor is serialized as the plain integer it was created from, and deserializing it goes through try_new(),
+// so the serialized form doesn't depend on how this crate represents divisors internally, and zero is rejected the same way it is everywhere else.
+
+use serde::{Serialize, Serializer, Deserialize, Deserializer};
+use serde::de::Error;
+
+use ::{StrengthReducedU8, StrengthReducedU16, StrengthReducedU32, StrengthReducedU64, StrengthReducedU128, StrengthReducedUsize};
+use ::{StrengthReducedI8, StrengthReducedI16, StrengthReducedI32, StrengthReducedI64, StrengthReducedI128, StrengthReducedIsize};
+use ::{StrengthReducedBranchfreeU32, StrengthReducedBranchfreeU64};
+
+macro_rules! impl_serde {
+    ($struct_name:ident, $primitive_type:ident) => (
+        impl Serialize for $struct_name {
+            #[inline]
+            fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+                self.get().serialize(serializer)
+            }
+        }
+
+        impl<'de> Deserialize<'de> for $struct_name {
+            #[inline]
+            fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+                let divisor = $primitive_type::deserialize(deserializer)?;
+                $struct_name::try_new(divisor).map_err(D::Error::custom)
+            }
+        }
+    )
+}
+
+impl_serde!(StrengthReducedU8, u8);
+impl_serde!(StrengthReducedU16, u16);
+impl_serde!(StrengthReducedU32, u32);
+impl_serde!(StrengthReducedU64, u64);
+impl_serde!(StrengthReducedU128, u128);
+impl_serde!(StrengthReducedUsize, usize);
+impl_serde!(StrengthReducedI8, i8);
+impl_serde!(StrengthReducedI16, i16);
+impl_serde!(StrengthReducedI32, i32);
+impl_serde!(StrengthReducedI64, i64);
+impl_serde!(StrengthReducedI128, i128);
+impl_serde!(StrengthReducedIsize, isize);
+impl_serde!(StrengthReducedBranchfreeU32, u32);
+impl_serde!(StrengthReducedBranchfreeU64, u64);
+
+/// Serializes a divisor together with its precomputed multiplier, for use with `#[serde(with = "strength_reduce::with_multiplier")]`.
+///
+/// The divisor is serialized as a `(divisor, multiplier)` tuple. Deserializing checks that the multiplier belongs to the divisor instead of recomputing it,
+/// which is much cheaper than `new()` for `StrengthReducedU64`, `StrengthReducedU128`, and their signed and `usize` counterparts.
+/// A zero divisor or a multiplier that doesn't match its divisor is rejected with an error.
+///
+/// The multiplier is an implementation detail, so unlike the plain divisor, data serialized this way isn't guaranteed to be readable by other versions of this crate.
+///
+/// # Example:
+/// ```
+/// # extern crate serde_derive;
+/// # extern crate serde_json;
+/// # extern crate strength_reduce;
+/// use serde_derive::{Serialize, Deserialize};
+/// use strength_reduce::StrengthReducedU128;
+///
+/// #[derive(Serialize, Deserialize)]
+/// struct Table {
+///     #[serde(with = "strength_reduce::with_multiplier")]
+///     row_length: StrengthReducedU128,
+/// }
+///
+/// let json = serde_json::to_string(&Table { row_length: StrengthReducedU128::new(12345) }).unwrap();
+/// let table: Table = serde_json::from_str(&json).unwrap();
+/// assert_eq!(table.row_length.get(), 12345);
+/// ```
+pub mod with_multiplier {
+    use serde::{Serialize, Serializer, Deserialize, Deserializer};
+    use serde::de::Error;
+
+    use ::DivisorError;
+    use ::{StrengthReducedU8, StrengthReducedU16, StrengthReducedU32, StrengthReducedU64, StrengthReducedU128, StrengthReducedUsize};
+    use ::{StrengthReducedI8, StrengthReducedI16, StrengthReducedI32, StrengthReducedI64, StrengthReducedI128, StrengthReducedIsize};
+    use ::{StrengthReducedBranchfreeU32, StrengthReducedBranchfreeU64};
+
+    mod private {
+        use serde::Serialize;
+        use serde::de::{DeserializeOwned, Error};
+
+        // Takes a divisor apart into the divisor and its multiplier, and puts it back together. This is sealed, so that the multiplier stays private to this crate
+        pub trait Parts: Sized {
+            type Int: Serialize + DeserializeOwned;
+            type Multiplier: Serialize + DeserializeOwned;
+
+            fn to_parts(&self) -> (Self::Int, Self::Multiplier);
+            fn from_parts<E: Error>(divisor: Self::Int, multiplier: Self::Multiplier) -> Result<Self, E>;
+        }
+    }
+
+    /// Implemented by every strength-reduced divisor type, so that they can all be serialized with their multiplier.
+    pub trait WithMultiplier: private::Parts {}
+
+    /// Serializes `divisor` as a `(divisor, multiplier)` tuple.
+    #[inline]
+    pub fn serialize<T: WithMultiplier, S: Serializer>(divisor: &T, serializer: S) -> Result<S::Ok, S::Error> {
+        divisor.to_parts().serialize(serializer)
+    }
+
+    /// Deserializes a `(divisor, multiplier)` tuple, returning an error if the divisor is zero or the multiplier doesn't match it.
+    #[inline]
+    pub fn deserialize<'de, T: WithMultiplier, D: Deserializer<'de>>(deserializer: D) -> Result<T, D::Error> {
+        let (divisor, multiplier) = <(T::Int, T::Multiplier)>::deserialize(deserializer)?;
+        T::from_parts(divisor, multiplier)
+    }
+
+    const INCONSISTENT_MULTIPLIER: &str = "the multiplier of a strength-reduced divisor doesn't match its divisor";
+
+    // StrengthReducedU64 and StrengthReducedU128 split their multipliers up, and the branch-free types keep theirs private to their own module, so those provide a method to get it instead
+    macro_rules! impl_unsigned_parts {
+        ($struct_name:ident, $primitive_type:ident, $multiplier_type:ty) => (
+            impl_unsigned_parts!($struct_name, $primitive_type, $multiplier_type, |divisor: &$struct_name| divisor.multiplier);
+        );
+        ($struct_name:ident, $primitive_type:ident, $multiplier_type:ty, $get_multiplier:expr) => (
+            impl private::Parts for $struct_name {
+                type Int = $primitive_type;
+                type Multiplier = $multiplier_type;
+
+                #[inline]
+                fn to_parts(&self) -> ($primitive_type, $multiplier_type) {
+                    (self.get(), $get_multiplier(self))
+                }
+
+                #[inline]
+                fn from_parts<E: Error>(divisor: $primitive_type, multiplier: $multiplier_type) -> Result<Self, E> {
+                    if divisor == 0 {
+                        Err(E::custom(DivisorError::Zero))
+                    } else {
+                        $struct_name::from_multiplier(divisor, multiplier).ok_or_else(|| E::custom(INCONSISTENT_MULTIPLIER))
+                    }
+                }
+            }
+            impl WithMultiplier for $struct_name {}
+        )
+    }
+
+    // The signed types are serialized with the multiplier of their absolute value
+    macro_rules! impl_signed_parts {
+        ($struct_name:ident, $unsigned_struct:ident, $primitive_type:ident, $unsigned_type:ident) => (
+            impl private::Parts for $struct_name {
+                type Int = $primitive_type;
+                type Multiplier = <$unsigned_struct as private::Parts>::Multiplier;
+
+                #[inline]
+                fn to_parts(&self) -> ($primitive_type, Self::Multiplier) {
+                    (self.get(), self.unsigned().to_parts().1)
+                }
+
+                #[inline]
+                fn from_parts<E: Error>(divisor: $primitive_type, multiplier: Self::Multiplier) -> Result<Self, E> {
+                    let unsigned = $unsigned_struct::from_parts(divisor.wrapping_abs() as $unsigned_type, multiplier)?;
+                    Ok($struct_name::from_unsigned(divisor, unsigned))
+                }
+            }
+            impl WithMultiplier for $struct_name {}
+        )
+    }
+
+    impl_unsigned_parts!(StrengthReducedU8, u8, u16);
+    impl_unsigned_parts!(StrengthReducedU16, u16, u32);
+    impl_unsigned_parts!(StrengthReducedU32, u32, u64);
+    impl_unsigned_parts!(StrengthReducedU64, u64, u128, StrengthReducedU64::full_multiplier);
+    #[cfg(target_pointer_width = "16")]
+    impl_unsigned_parts!(StrengthReducedUsize, usize, u32);
+    #[cfg(target_pointer_width = "32")]
+    impl_unsigned_parts!(StrengthReducedUsize, usize, u64);
+    #[cfg(target_pointer_width = "64")]
+    impl_unsigned_parts!(StrengthReducedUsize, usize, u128, StrengthReducedUsize::full_multiplier);
+
+    impl_unsigned_parts!(StrengthReducedU128, u128, (u128, u128), StrengthReducedU128::full_multiplier);
+
+    impl_signed_parts!(StrengthReducedI8, StrengthReducedU8, i8, u8);
+    impl_signed_parts!(StrengthReducedI16, StrengthReducedU16, i16, u16);
+    impl_signed_parts!(StrengthReducedI32, StrengthReducedU32, i32, u32);
+    impl_signed_parts!(StrengthReducedI64, StrengthReducedU64, i64, u64);
+    impl_signed_parts!(StrengthReducedI128, StrengthReducedU128, i128, u128);
+    impl_signed_parts!(StrengthReducedIsize, StrengthReducedUsize, isize, usize);
+
+    impl_unsigned_parts!(StrengthReducedBranchfreeU32, u32, u64, StrengthReducedBranchfreeU32::multiplier);
+    impl_unsigned_parts!(StrengthReducedBranchfreeU64, u64, u64, StrengthReducedBranchfreeU64::multiplier);
+}
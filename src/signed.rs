@@ -0,0 +1,181 @@
+use core::ops::{Div, Rem};
+
+use ::{StrengthReducedU8, StrengthReducedU16, StrengthReducedU32, StrengthReducedU64, StrengthReducedU128, StrengthReducedUsize};
+
+// Signed division is unsigned division of the absolute values, with the signs fixed up afterwards.
+// Truncating division rounds toward zero, so the quotient is negative iff exactly one operand is negative, and the remainder takes the sign of the numerator.
+macro_rules! strength_reduced_signed {
+    ($struct_name:ident, $unsigned_struct:ident, $primitive_type:ident, $unsigned_type:ident) => (
+        /// Implements signed division and modulo via multiplication and shifts.
+        ///
+        /// Division truncates toward zero, and the remainder has the same sign as the numerator, exactly like the `/` and `%` operators on primitive integers.
+        ///
+        /// Creating an instance of this struct is more expensive than a single division, but if the division is repeated,
+        /// this version will be several times faster than naive division.
+        #[derive(Clone, Copy, Debug)]
+        pub struct $struct_name {
+            unsigned: $unsigned_struct,
+            divisor: $primitive_type,
+        }
+        impl $struct_name {
+            /// Creates a new divisor instance.
+            ///
+            /// If possible, avoid calling new() from an inner loop: The intended usage is to create an instance of this struct outside the loop, and use it for division and remainders inside the loop.
+            ///
+            /// # Panics:
+            ///
+            /// Panics if `divisor` is 0
+            #[inline]
+            pub const fn new(divisor: $primitive_type) -> Self {
+                assert!(divisor != 0);
+
+                // wrapping_abs maps MIN to itself, which is exactly |MIN| once it's reinterpreted as unsigned
+                let unsigned = $unsigned_struct::new(divisor.wrapping_abs() as $unsigned_type);
+                Self{ unsigned, divisor }
+            }
+
+            // The serialization code rebuilds the unsigned divisor from its multiplier, and needs to get at it and put it back
+            #[cfg(feature = "serde")]
+            pub(crate) fn unsigned(&self) -> $unsigned_struct {
+                self.unsigned
+            }
+
+            #[cfg(feature = "serde")]
+            pub(crate) fn from_unsigned(divisor: $primitive_type, unsigned: $unsigned_struct) -> Self {
+                debug_assert_eq!(unsigned.get(), divisor.wrapping_abs() as $unsigned_type);
+                Self{ unsigned, divisor }
+            }
+
+            /// Simultaneous truncated integer division and modulus.
+            /// Returns `(quotient, remainder)`.
+            ///
+            /// # Panics:
+            ///
+            /// Panics if `numerator` is the minimum value of its type and the divisor is -1, because the quotient overflows
+            #[inline]
+            pub fn div_rem(numerator: $primitive_type, denom: Self) -> ($primitive_type, $primitive_type) {
+                let (abs_quotient, abs_remainder) = $unsigned_struct::div_rem(numerator.wrapping_abs() as $unsigned_type, denom.unsigned);
+                let quotient = Self::apply_quotient_sign(abs_quotient as $primitive_type, numerator, denom.divisor);
+                let remainder = abs_remainder as $primitive_type;
+                let remainder = if numerator < 0 { remainder.wrapping_neg() } else { remainder };
+                (quotient, remainder)
+            }
+
+            /// Retrieve the value used to create this struct
+            #[inline]
+            pub fn get(&self) -> $primitive_type {
+                self.divisor
+            }
+
+            /// Division that rounds the quotient toward negative infinity.
+            ///
+            /// # Panics:
+            ///
+            /// Panics if `numerator` is the minimum value of its type and the divisor is -1, because the quotient overflows
+            #[inline]
+            pub fn div_floor(numerator: $primitive_type, denom: Self) -> $primitive_type {
+                let (quotient, remainder) = Self::div_rem(numerator, denom);
+                // truncation rounded toward zero, so if the exact quotient was negative and not an integer, we rounded the wrong way
+                if remainder != 0 && (remainder ^ denom.divisor) < 0 { quotient - 1 } else { quotient }
+            }
+
+            /// Division that rounds the quotient toward positive infinity.
+            ///
+            /// # Panics:
+            ///
+            /// Panics if `numerator` is the minimum value of its type and the divisor is -1, because the quotient overflows
+            #[inline]
+            pub fn div_ceil(numerator: $primitive_type, denom: Self) -> $primitive_type {
+                let (quotient, remainder) = Self::div_rem(numerator, denom);
+                if remainder != 0 && (remainder ^ denom.divisor) >= 0 { quotient + 1 } else { quotient }
+            }
+
+            /// Division that rounds the quotient to the nearest integer, with ties rounded away from zero.
+            ///
+            /// # Panics:
+            ///
+            /// Panics if `numerator` is the minimum value of its type and the divisor is -1, because the quotient overflows
+            #[inline]
+            pub fn div_round(numerator: $primitive_type, denom: Self) -> $primitive_type {
+                let (quotient, remainder) = Self::div_rem(numerator, denom);
+                let abs_remainder = remainder.wrapping_abs() as $unsigned_type;
+                let abs_divisor = denom.divisor.wrapping_abs() as $unsigned_type;
+
+                // round away from zero if |remainder| >= |divisor| / 2, written so that it can't overflow
+                if abs_remainder >= abs_divisor - abs_remainder {
+                    if (numerator ^ denom.divisor) < 0 { quotient - 1 } else { quotient + 1 }
+                } else {
+                    quotient
+                }
+            }
+
+            /// Euclidean division: the quotient that makes `rem_euclid` non-negative.
+            ///
+            /// # Panics:
+            ///
+            /// Panics if `numerator` is the minimum value of its type and the divisor is -1, because the quotient overflows
+            #[inline]
+            pub fn div_euclid(numerator: $primitive_type, denom: Self) -> $primitive_type {
+                let (quotient, remainder) = Self::div_rem(numerator, denom);
+                if remainder < 0 {
+                    if denom.divisor > 0 { quotient - 1 } else { quotient + 1 }
+                } else {
+                    quotient
+                }
+            }
+
+            /// Euclidean remainder, which is always non-negative.
+            ///
+            /// # Panics:
+            ///
+            /// Panics if `numerator` is the minimum value of its type and the divisor is -1, to match the primitive `rem_euclid`
+            #[inline]
+            pub fn rem_euclid(numerator: $primitive_type, denom: Self) -> $primitive_type {
+                let remainder = numerator % denom;
+                // |divisor| doesn't fit when the divisor is MIN, but remainder + |divisor| does, so wrapping arithmetic gets the right answer
+                if remainder < 0 { remainder.wrapping_add(denom.divisor.wrapping_abs()) } else { remainder }
+            }
+
+            #[inline]
+            fn apply_quotient_sign(abs_quotient: $primitive_type, numerator: $primitive_type, divisor: $primitive_type) -> $primitive_type {
+                if (numerator ^ divisor) < 0 {
+                    abs_quotient.wrapping_neg()
+                } else {
+                    // The only positive quotient that doesn't fit is MIN / -1, which shows up here as |MIN| reinterpreted as MIN
+                    assert!(abs_quotient >= 0, "attempt to divide with overflow");
+                    abs_quotient
+                }
+            }
+        }
+
+        impl Div<$struct_name> for $primitive_type {
+            type Output = $primitive_type;
+
+            #[inline]
+            fn div(self, rhs: $struct_name) -> Self::Output {
+                let abs_quotient = (self.wrapping_abs() as $unsigned_type / rhs.unsigned) as $primitive_type;
+                $struct_name::apply_quotient_sign(abs_quotient, self, rhs.divisor)
+            }
+        }
+
+        impl Rem<$struct_name> for $primitive_type {
+            type Output = $primitive_type;
+
+            #[inline]
+            fn rem(self, rhs: $struct_name) -> Self::Output {
+                // The remainder of MIN % -1 is mathematically 0, but the primitive operator panics, so we do too
+                assert!(!(self == $primitive_type::MIN && rhs.divisor == -1), "attempt to calculate the remainder with overflow");
+
+                let remainder = (self.wrapping_abs() as $unsigned_type % rhs.unsigned) as $primitive_type;
+                if self < 0 { remainder.wrapping_neg() } else { remainder }
+            }
+        }
+    )
+}
+
+strength_reduced_signed!(StrengthReducedI8, StrengthReducedU8, i8, u8);
+strength_reduced_signed!(StrengthReducedI16, StrengthReducedU16, i16, u16);
+strength_reduced_signed!(StrengthReducedI32, StrengthReducedU32, i32, u32);
+strength_reduced_signed!(StrengthReducedI64, StrengthReducedU64, i64, u64);
+strength_reduced_signed!(StrengthReducedI128, StrengthReducedU128, i128, u128);
+strength_reduced_signed!(StrengthReducedIsize, StrengthReducedUsize, isize, usize);
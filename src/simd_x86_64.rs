@@ -0,0 +1,388 @@
+// Explicit SSE2 and AVX2 kernels for the slice functions of StrengthReducedU16, StrengthReducedU32 and StrengthReducedU64.
+// These compute exactly the same thing as the scalar non-power-of-two paths, lane by lane. SSE2 is part of the x86_64 baseline, so it's always available,
+// and AVX2 is detected at runtime if the `std` feature is enabled, or at compile time otherwise.
+//
+// Each kernel processes as many whole vectors as fit in the input and returns how many elements that was, leaving the tail to the scalar loop.
+// `quotients` and `remainders` may each be null, in which case that output isn't computed, and may also be the same pointer as `numerators`, for the in-place functions.
+
+use core::arch::x86_64::*;
+
+use ::{StrengthReducedU16, StrengthReducedU32, StrengthReducedU64};
+
+#[inline]
+fn avx2_available() -> bool {
+    #[cfg(feature = "std")]
+    {
+        ::std::is_x86_feature_detected!("avx2")
+    }
+    #[cfg(not(feature = "std"))]
+    {
+        cfg!(target_feature = "avx2")
+    }
+}
+
+impl StrengthReducedU16 {
+    #[inline]
+    pub(crate) unsafe fn div_rem_slice_simd(numerators: *const u16, quotients: *mut u16, remainders: *mut u16, len: usize, denom: Self) -> usize {
+        if avx2_available() {
+            div_rem_u16_avx2(numerators, quotients, remainders, len, denom)
+        } else {
+            div_rem_u16_sse2(numerators, quotients, remainders, len, denom)
+        }
+    }
+}
+
+impl StrengthReducedU32 {
+    #[inline]
+    pub(crate) unsafe fn div_rem_slice_simd(numerators: *const u32, quotients: *mut u32, remainders: *mut u32, len: usize, denom: Self) -> usize {
+        if avx2_available() {
+            div_rem_u32_avx2(numerators, quotients, remainders, len, denom)
+        } else {
+            div_rem_u32_sse2(numerators, quotients, remainders, len, denom)
+        }
+    }
+}
+
+impl StrengthReducedU64 {
+    #[inline]
+    pub(crate) unsafe fn div_rem_slice_simd(numerators: *const u64, quotients: *mut u64, remainders: *mut u64, len: usize, denom: Self) -> usize {
+        if avx2_available() {
+            div_rem_u64_avx2(numerators, quotients, remainders, len, denom)
+        } else {
+            div_rem_u64_sse2(numerators, quotients, remainders, len, denom)
+        }
+    }
+}
+
+
+
+// u16: the quotient is the upper half of numerator * multiplier_hi + mulhi(numerator, multiplier_lo), a 32-bit sum.
+// We have 16-bit high and low multiplies, so we compute the upper half directly and add the carry out of the lower half.
+#[inline(always)]
+unsafe fn divide_u16_sse2(numerator: __m128i, multiplier_hi: __m128i, multiplier_lo: __m128i) -> __m128i {
+    let product_hi = _mm_mulhi_epu16(numerator, multiplier_hi);
+    let product_lo = _mm_mullo_epi16(numerator, multiplier_hi);
+    let addend = _mm_mulhi_epu16(numerator, multiplier_lo);
+    let sum_lo = _mm_add_epi16(product_lo, addend);
+
+    // SSE2 only has signed comparisons, so flip the sign bits to compare unsigned. The add carried if the sum wrapped around below the addend
+    let sign_bit = _mm_set1_epi16(i16::MIN);
+    let carry_mask = _mm_cmpgt_epi16(_mm_xor_si128(addend, sign_bit), _mm_xor_si128(sum_lo, sign_bit));
+
+    // the mask is -1 wherever there was a carry
+    _mm_sub_epi16(product_hi, carry_mask)
+}
+
+unsafe fn div_rem_u16_sse2(numerators: *const u16, quotients: *mut u16, remainders: *mut u16, len: usize, denom: StrengthReducedU16) -> usize {
+    const LANES: usize = 8;
+    let multiplier_hi = _mm_set1_epi16((denom.multiplier >> 16) as i16);
+    let multiplier_lo = _mm_set1_epi16(denom.multiplier as u16 as i16);
+    let divisor = _mm_set1_epi16(denom.divisor as i16);
+
+    let mut index = 0;
+    while index + LANES <= len {
+        let numerator = _mm_loadu_si128(numerators.add(index) as *const __m128i);
+        let quotient = divide_u16_sse2(numerator, multiplier_hi, multiplier_lo);
+        if !quotients.is_null() {
+            _mm_storeu_si128(quotients.add(index) as *mut __m128i, quotient);
+        }
+        if !remainders.is_null() {
+            let remainder = _mm_sub_epi16(numerator, _mm_mullo_epi16(quotient, divisor));
+            _mm_storeu_si128(remainders.add(index) as *mut __m128i, remainder);
+        }
+        index += LANES;
+    }
+    index
+}
+
+#[inline]
+#[target_feature(enable = "avx2")]
+unsafe fn divide_u16_avx2(numerator: __m256i, multiplier_hi: __m256i, multiplier_lo: __m256i) -> __m256i {
+    let product_hi = _mm256_mulhi_epu16(numerator, multiplier_hi);
+    let product_lo = _mm256_mullo_epi16(numerator, multiplier_hi);
+    let addend = _mm256_mulhi_epu16(numerator, multiplier_lo);
+    let sum_lo = _mm256_add_epi16(product_lo, addend);
+
+    let sign_bit = _mm256_set1_epi16(i16::MIN);
+    let carry_mask = _mm256_cmpgt_epi16(_mm256_xor_si256(addend, sign_bit), _mm256_xor_si256(sum_lo, sign_bit));
+
+    _mm256_sub_epi16(product_hi, carry_mask)
+}
+
+#[target_feature(enable = "avx2")]
+unsafe fn div_rem_u16_avx2(numerators: *const u16, quotients: *mut u16, remainders: *mut u16, len: usize, denom: StrengthReducedU16) -> usize {
+    const LANES: usize = 16;
+    let multiplier_hi = _mm256_set1_epi16((denom.multiplier >> 16) as i16);
+    let multiplier_lo = _mm256_set1_epi16(denom.multiplier as u16 as i16);
+    let divisor = _mm256_set1_epi16(denom.divisor as i16);
+
+    let mut index = 0;
+    while index + LANES <= len {
+        let numerator = _mm256_loadu_si256(numerators.add(index) as *const __m256i);
+        let quotient = divide_u16_avx2(numerator, multiplier_hi, multiplier_lo);
+        if !quotients.is_null() {
+            _mm256_storeu_si256(quotients.add(index) as *mut __m256i, quotient);
+        }
+        if !remainders.is_null() {
+            let remainder = _mm256_sub_epi16(numerator, _mm256_mullo_epi16(quotient, divisor));
+            _mm256_storeu_si256(remainders.add(index) as *mut __m256i, remainder);
+        }
+        index += LANES;
+    }
+    index
+}
+
+
+
+// u32: the 32x32->64 multiply only reads the even 32-bit lanes, so the odd lanes are shifted down and multiplied separately.
+// The quotient is the upper half of each 64-bit sum, which is already in the right place for the odd lanes.
+#[inline(always)]
+unsafe fn divide_u32_sse2(numerator: __m128i, multiplier_hi: __m128i, multiplier_lo: __m128i) -> __m128i {
+    let numerator_odd = _mm_srli_epi64(numerator, 32);
+    let sum_even = _mm_add_epi64(_mm_mul_epu32(numerator, multiplier_hi), _mm_srli_epi64(_mm_mul_epu32(numerator, multiplier_lo), 32));
+    let sum_odd = _mm_add_epi64(_mm_mul_epu32(numerator_odd, multiplier_hi), _mm_srli_epi64(_mm_mul_epu32(numerator_odd, multiplier_lo), 32));
+
+    let upper_mask = _mm_set1_epi64x(0xFFFFFFFF00000000u64 as i64);
+    _mm_or_si128(_mm_srli_epi64(sum_even, 32), _mm_and_si128(sum_odd, upper_mask))
+}
+
+// SSE2 has no 32-bit low multiply, so build one out of two 32x32->64 multiplies
+#[inline(always)]
+unsafe fn multiply_low_u32_sse2(a: __m128i, b: __m128i) -> __m128i {
+    let product_even = _mm_mul_epu32(a, b);
+    let product_odd = _mm_mul_epu32(_mm_srli_epi64(a, 32), _mm_srli_epi64(b, 32));
+
+    let lower_mask = _mm_set1_epi64x(0xFFFFFFFF);
+    _mm_or_si128(_mm_and_si128(product_even, lower_mask), _mm_slli_epi64(product_odd, 32))
+}
+
+unsafe fn div_rem_u32_sse2(numerators: *const u32, quotients: *mut u32, remainders: *mut u32, len: usize, denom: StrengthReducedU32) -> usize {
+    const LANES: usize = 4;
+    let multiplier_hi = _mm_set1_epi32((denom.multiplier >> 32) as i32);
+    let multiplier_lo = _mm_set1_epi32(denom.multiplier as u32 as i32);
+    let divisor = _mm_set1_epi32(denom.divisor as i32);
+
+    let mut index = 0;
+    while index + LANES <= len {
+        let numerator = _mm_loadu_si128(numerators.add(index) as *const __m128i);
+        let quotient = divide_u32_sse2(numerator, multiplier_hi, multiplier_lo);
+        if !quotients.is_null() {
+            _mm_storeu_si128(quotients.add(index) as *mut __m128i, quotient);
+        }
+        if !remainders.is_null() {
+            let remainder = _mm_sub_epi32(numerator, multiply_low_u32_sse2(quotient, divisor));
+            _mm_storeu_si128(remainders.add(index) as *mut __m128i, remainder);
+        }
+        index += LANES;
+    }
+    index
+}
+
+#[inline]
+#[target_feature(enable = "avx2")]
+unsafe fn divide_u32_avx2(numerator: __m256i, multiplier_hi: __m256i, multiplier_lo: __m256i) -> __m256i {
+    let numerator_odd = _mm256_srli_epi64(numerator, 32);
+    let sum_even = _mm256_add_epi64(_mm256_mul_epu32(numerator, multiplier_hi), _mm256_srli_epi64(_mm256_mul_epu32(numerator, multiplier_lo), 32));
+    let sum_odd = _mm256_add_epi64(_mm256_mul_epu32(numerator_odd, multiplier_hi), _mm256_srli_epi64(_mm256_mul_epu32(numerator_odd, multiplier_lo), 32));
+
+    // the odd quotients are in the upper half of each 64-bit lane, and the even quotients are in the upper half of each shifted-down lane
+    _mm256_blend_epi32(_mm256_srli_epi64(sum_even, 32), sum_odd, 0b10101010)
+}
+
+#[target_feature(enable = "avx2")]
+unsafe fn div_rem_u32_avx2(numerators: *const u32, quotients: *mut u32, remainders: *mut u32, len: usize, denom: StrengthReducedU32) -> usize {
+    const LANES: usize = 8;
+    let multiplier_hi = _mm256_set1_epi32((denom.multiplier >> 32) as i32);
+    let multiplier_lo = _mm256_set1_epi32(denom.multiplier as u32 as i32);
+    let divisor = _mm256_set1_epi32(denom.divisor as i32);
+
+    let mut index = 0;
+    while index + LANES <= len {
+        let numerator = _mm256_loadu_si256(numerators.add(index) as *const __m256i);
+        let quotient = divide_u32_avx2(numerator, multiplier_hi, multiplier_lo);
+        if !quotients.is_null() {
+            _mm256_storeu_si256(quotients.add(index) as *mut __m256i, quotient);
+        }
+        if !remainders.is_null() {
+            let remainder = _mm256_sub_epi32(numerator, _mm256_mullo_epi32(quotient, divisor));
+            _mm256_storeu_si256(remainders.add(index) as *mut __m256i, remainder);
+        }
+        index += LANES;
+    }
+    index
+}
+
+
+
+// u64: neither instruction set has a 64-bit multiply, so every 64x64 product is assembled from four 32x32->64 partial products.
+// Returns the upper 64 bits of each lane of `a` times `b`. `b_hi` must hold the upper 32 bits of `b` in the lower half of each lane.
+#[inline(always)]
+unsafe fn multiply_high_u64_sse2(a: __m128i, b: __m128i, b_hi: __m128i) -> __m128i {
+    let a_hi = _mm_srli_epi64(a, 32);
+    let product_lo_lo = _mm_mul_epu32(a, b);
+    let product_lo_hi = _mm_mul_epu32(a, b_hi);
+    let product_hi_lo = _mm_mul_epu32(a_hi, b);
+    let product_hi_hi = _mm_mul_epu32(a_hi, b_hi);
+
+    // the sum of the middle 32-bit column can't overflow 64 bits, and its upper half carries into the upper product
+    let lower_mask = _mm_set1_epi64x(0xFFFFFFFF);
+    let middle = _mm_add_epi64(_mm_add_epi64(_mm_srli_epi64(product_lo_lo, 32), _mm_and_si128(product_lo_hi, lower_mask)), _mm_and_si128(product_hi_lo, lower_mask));
+
+    _mm_add_epi64(_mm_add_epi64(product_hi_hi, _mm_srli_epi64(product_lo_hi, 32)), _mm_add_epi64(_mm_srli_epi64(product_hi_lo, 32), _mm_srli_epi64(middle, 32)))
+}
+
+// The lower 64 bits of each lane of `a` times `b`, which only needs three of the partial products
+#[inline(always)]
+unsafe fn multiply_low_u64_sse2(a: __m128i, b: __m128i, b_hi: __m128i) -> __m128i {
+    let cross = _mm_add_epi64(_mm_mul_epu32(a, b_hi), _mm_mul_epu32(_mm_srli_epi64(a, 32), b));
+    _mm_add_epi64(_mm_mul_epu32(a, b), _mm_slli_epi64(cross, 32))
+}
+
+// Same steps as the scalar code: mulhi(numerator, multiplier), then either a plain shift, or the overflow-free add of the numerator before the shift.
+// The shift is the same for every lane, so it's passed in the lower 64 bits of `shift` for the variable shift instructions
+#[inline(always)]
+unsafe fn divide_u64_sse2(numerator: __m128i, multiplier: (__m128i, __m128i), shift: __m128i, add_indicator: bool) -> __m128i {
+    let quotient = multiply_high_u64_sse2(numerator, multiplier.0, multiplier.1);
+    if add_indicator {
+        _mm_srl_epi64(_mm_add_epi64(_mm_srli_epi64(_mm_sub_epi64(numerator, quotient), 1), quotient), shift)
+    } else {
+        _mm_srl_epi64(quotient, shift)
+    }
+}
+
+unsafe fn div_rem_u64_sse2(numerators: *const u64, quotients: *mut u64, remainders: *mut u64, len: usize, denom: StrengthReducedU64) -> usize {
+    const LANES: usize = 2;
+    let multiplier = (_mm_set1_epi64x(denom.multiplier as i64), _mm_set1_epi64x((denom.multiplier >> 32) as i64));
+    let shift = _mm_cvtsi32_si128(denom.shift as i32);
+    let divisor = (_mm_set1_epi64x(denom.divisor as i64), _mm_set1_epi64x((denom.divisor >> 32) as i64));
+
+    let mut index = 0;
+    while index + LANES <= len {
+        let numerator = _mm_loadu_si128(numerators.add(index) as *const __m128i);
+        let quotient = divide_u64_sse2(numerator, multiplier, shift, denom.add_indicator);
+        if !quotients.is_null() {
+            _mm_storeu_si128(quotients.add(index) as *mut __m128i, quotient);
+        }
+        if !remainders.is_null() {
+            let remainder = _mm_sub_epi64(numerator, multiply_low_u64_sse2(quotient, divisor.0, divisor.1));
+            _mm_storeu_si128(remainders.add(index) as *mut __m128i, remainder);
+        }
+        index += LANES;
+    }
+    index
+}
+
+#[inline]
+#[target_feature(enable = "avx2")]
+unsafe fn multiply_high_u64_avx2(a: __m256i, b: __m256i, b_hi: __m256i) -> __m256i {
+    let a_hi = _mm256_srli_epi64(a, 32);
+    let product_lo_lo = _mm256_mul_epu32(a, b);
+    let product_lo_hi = _mm256_mul_epu32(a, b_hi);
+    let product_hi_lo = _mm256_mul_epu32(a_hi, b);
+    let product_hi_hi = _mm256_mul_epu32(a_hi, b_hi);
+
+    let lower_mask = _mm256_set1_epi64x(0xFFFFFFFF);
+    let middle = _mm256_add_epi64(_mm256_add_epi64(_mm256_srli_epi64(product_lo_lo, 32), _mm256_and_si256(product_lo_hi, lower_mask)), _mm256_and_si256(product_hi_lo, lower_mask));
+
+    _mm256_add_epi64(_mm256_add_epi64(product_hi_hi, _mm256_srli_epi64(product_lo_hi, 32)), _mm256_add_epi64(_mm256_srli_epi64(product_hi_lo, 32), _mm256_srli_epi64(middle, 32)))
+}
+
+#[inline]
+#[target_feature(enable = "avx2")]
+unsafe fn multiply_low_u64_avx2(a: __m256i, b: __m256i, b_hi: __m256i) -> __m256i {
+    let cross = _mm256_add_epi64(_mm256_mul_epu32(a, b_hi), _mm256_mul_epu32(_mm256_srli_epi64(a, 32), b));
+    _mm256_add_epi64(_mm256_mul_epu32(a, b), _mm256_slli_epi64(cross, 32))
+}
+
+#[inline]
+#[target_feature(enable = "avx2")]
+unsafe fn divide_u64_avx2(numerator: __m256i, multiplier: (__m256i, __m256i), shift: __m128i, add_indicator: bool) -> __m256i {
+    let quotient = multiply_high_u64_avx2(numerator, multiplier.0, multiplier.1);
+    if add_indicator {
+        _mm256_srl_epi64(_mm256_add_epi64(_mm256_srli_epi64(_mm256_sub_epi64(numerator, quotient), 1), quotient), shift)
+    } else {
+        _mm256_srl_epi64(quotient, shift)
+    }
+}
+
+#[target_feature(enable = "avx2")]
+unsafe fn div_rem_u64_avx2(numerators: *const u64, quotients: *mut u64, remainders: *mut u64, len: usize, denom: StrengthReducedU64) -> usize {
+    const LANES: usize = 4;
+    let multiplier = (_mm256_set1_epi64x(denom.multiplier as i64), _mm256_set1_epi64x((denom.multiplier >> 32) as i64));
+    let shift = _mm_cvtsi32_si128(denom.shift as i32);
+    let divisor = (_mm256_set1_epi64x(denom.divisor as i64), _mm256_set1_epi64x((denom.divisor >> 32) as i64));
+
+    let mut index = 0;
+    while index + LANES <= len {
+        let numerator = _mm256_loadu_si256(numerators.add(index) as *const __m256i);
+        let quotient = divide_u64_avx2(numerator, multiplier, shift, denom.add_indicator);
+        if !quotients.is_null() {
+            _mm256_storeu_si256(quotients.add(index) as *mut __m256i, quotient);
+        }
+        if !remainders.is_null() {
+            let remainder = _mm256_sub_epi64(numerator, multiply_low_u64_avx2(quotient, divisor.0, divisor.1));
+            _mm256_storeu_si256(remainders.add(index) as *mut __m256i, remainder);
+        }
+        index += LANES;
+    }
+    index
+}
+
+
+
+#[cfg(test)]
+mod unit_tests {
+    use super::*;
+
+    // The slice functions only ever run the best kernel for this machine, so test both instruction sets directly against the scalar operators
+    macro_rules! simd_kernel_test {
+        ($test_name:ident, $struct_name:ident, $primitive_type:ident, $sse2_fn:ident, $avx2_fn:ident) => (
+            #[test]
+            fn $test_name() {
+                let max = $primitive_type::MAX;
+                let divisors = [3, 5, 6, 7, 10, 11, 641, max / 3, max / 2, max - 1, max];
+
+                let mut numerators = [0 as $primitive_type; 67];
+                for (i, numerator) in numerators.iter_mut().enumerate() {
+                    *numerator = match i % 4 {
+                        0 => i as $primitive_type,
+                        1 => max - i as $primitive_type,
+                        2 => max / (i as $primitive_type + 1),
+                        _ => (i as $primitive_type).wrapping_mul(0x9E3779B97F4A7C15u64 as $primitive_type),
+                    };
+                }
+
+                for &divisor in &divisors {
+                    let reduced_divisor = $struct_name::new(divisor);
+
+                    let mut kernels: [Option<unsafe fn(*const $primitive_type, *mut $primitive_type, *mut $primitive_type, usize, $struct_name) -> usize>; 2] = [Some($sse2_fn), None];
+                    if avx2_available() {
+                        kernels[1] = Some($avx2_fn);
+                    }
+
+                    for kernel in kernels.iter().flatten() {
+                        let mut quotients = [0; 67];
+                        let mut remainders = [0; 67];
+                        let processed = unsafe { kernel(numerators.as_ptr(), quotients.as_mut_ptr(), remainders.as_mut_ptr(), numerators.len(), reduced_divisor) };
+                        assert!(processed > numerators.len() - 16 && processed <= numerators.len());
+
+                        for i in 0..processed {
+                            assert_eq!(numerators[i] / divisor, quotients[i], "Divide failed with numerator: {}, divisor: {}", numerators[i], divisor);
+                            assert_eq!(numerators[i] % divisor, remainders[i], "Modulo failed with numerator: {}, divisor: {}", numerators[i], divisor);
+                        }
+
+                        // in place, with only one output
+                        let mut values = numerators;
+                        let values_ptr = values.as_mut_ptr();
+                        unsafe { kernel(values_ptr, values_ptr, core::ptr::null_mut(), values.len(), reduced_divisor) };
+                        assert_eq!(&quotients[..processed], &values[..processed]);
+                    }
+                }
+            }
+        )
+    }
+
+    simd_kernel_test!(test_simd_kernels_u16, StrengthReducedU16, u16, div_rem_u16_sse2, div_rem_u16_avx2);
+    simd_kernel_test!(test_simd_kernels_u32, StrengthReducedU32, u32, div_rem_u32_sse2, div_rem_u32_avx2);
+    simd_kernel_test!(test_simd_kernels_u64, StrengthReducedU64, u64, div_rem_u64_sse2, div_rem_u64_avx2);
+}
@@ -0,0 +1,128 @@
+#[macro_use]
+extern crate proptest;
+extern crate strength_reduce;
+extern crate num_bigint;
+
+use num_bigint::BigUint;
+use proptest::test_runner::Config;
+use strength_reduce::{StrengthReducedU32, StrengthReducedU64};
+
+// BigUint stores its digits as little-endian u32s, so u64 limbs are split in half before handing them over
+fn u32_limbs_to_biguint(limbs: &[u32]) -> BigUint {
+    BigUint::from_slice(limbs)
+}
+
+fn u64_limbs_to_biguint(limbs: &[u64]) -> BigUint {
+    let digits: Vec<u32> = limbs.iter().flat_map(|&limb| vec![limb as u32, (limb >> 32) as u32]).collect();
+    BigUint::new(digits)
+}
+
+macro_rules! limb_division_proptest {
+    ($test_name:ident, $struct_name:ident, $primitive_type:ident, $to_biguint:ident) => (
+        mod $test_name {
+            use super::*;
+            use proptest::collection::vec;
+            use proptest::sample::select;
+
+            fn assert_limb_division_equivalence(divisor: $primitive_type, numerator: &[$primitive_type]) {
+                let reduced_divisor = $struct_name::new(divisor);
+                let big_numerator = $to_biguint(numerator);
+                let expected_quotient = &big_numerator / BigUint::from(divisor);
+                let expected_remainder = &big_numerator % BigUint::from(divisor);
+
+                let mut quotient = vec![0; numerator.len()];
+                let remainder = $struct_name::div_rem_limbs(numerator, reduced_divisor, &mut quotient);
+                assert_eq!(expected_quotient, $to_biguint(&quotient), "div_rem_limbs divide failed with numerator: {}, divisor: {}", big_numerator, divisor);
+                assert_eq!(expected_remainder, BigUint::from(remainder), "div_rem_limbs modulo failed with numerator: {}, divisor: {}", big_numerator, divisor);
+
+                let mut limbs = numerator.to_vec();
+                let remainder = $struct_name::div_rem_limbs_in_place(&mut limbs, reduced_divisor);
+                assert_eq!(expected_quotient, $to_biguint(&limbs), "div_rem_limbs_in_place divide failed with numerator: {}, divisor: {}", big_numerator, divisor);
+                assert_eq!(expected_remainder, BigUint::from(remainder), "div_rem_limbs_in_place modulo failed with numerator: {}, divisor: {}", big_numerator, divisor);
+
+                let remainder = $struct_name::rem_limbs(numerator, reduced_divisor);
+                assert_eq!(expected_remainder, BigUint::from(remainder), "rem_limbs failed with numerator: {}, divisor: {}", big_numerator, divisor);
+            }
+
+            proptest! {
+                #![proptest_config(Config::with_cases(10_000))]
+
+                #[test]
+                fn fully_generated_inputs_are_div_rem_equivalent(divisor in 1..$primitive_type::MAX, numerator in vec(0..$primitive_type::MAX, 0..20)) {
+                    assert_limb_division_equivalence(divisor, &numerator);
+                }
+
+                #[test]
+                fn generated_numerators_with_edge_case_divisors_are_div_rem_equivalent(
+                        divisor in select(vec![1 as $primitive_type, 2 as $primitive_type, 3 as $primitive_type, $primitive_type::MAX / 2, $primitive_type::MAX - 1, $primitive_type::MAX]),
+                        numerator in vec(0..$primitive_type::MAX, 0..20)) {
+                    assert_limb_division_equivalence(divisor, &numerator);
+                }
+
+                #[test]
+                fn generated_divisors_with_edge_case_numerators_are_div_rem_equivalent(
+                        divisor in 1..$primitive_type::MAX,
+                        limb in select(vec![0 as $primitive_type, 1 as $primitive_type, $primitive_type::MAX - 1, $primitive_type::MAX]),
+                        len in 0usize..20) {
+                    assert_limb_division_equivalence(divisor, &vec![limb; len]);
+                }
+            }
+        }
+    )
+}
+
+limb_division_proptest!(strength_reduced_u32_limbs, StrengthReducedU32, u32, u32_limbs_to_biguint);
+limb_division_proptest!(strength_reduced_u64_limbs, StrengthReducedU64, u64, u64_limbs_to_biguint);
+
+#[cfg(feature = "num-bigint")]
+macro_rules! biguint_division_proptest {
+    ($test_name:ident, $struct_name:ident, $primitive_type:ident) => (
+        mod $test_name {
+            use super::*;
+            use proptest::collection::vec;
+
+            proptest! {
+                #![proptest_config(Config::with_cases(10_000))]
+
+                #[test]
+                fn generated_biguints_are_div_rem_equivalent(divisor in 1..$primitive_type::MAX, digits in vec(0..u32::MAX, 0..40)) {
+                    let numerator = BigUint::new(digits);
+                    let reduced_divisor = $struct_name::new(divisor);
+                    let expected_div = &numerator / BigUint::from(divisor);
+                    let expected_rem = &numerator % BigUint::from(divisor);
+
+                    assert_eq!(expected_div, &numerator / reduced_divisor, "Divide failed with numerator: {}, divisor: {}", numerator, divisor);
+                    assert_eq!(expected_rem, &numerator % reduced_divisor, "Modulo failed with numerator: {}, divisor: {}", numerator, divisor);
+                    assert_eq!(expected_rem, BigUint::from($struct_name::rem_biguint(&numerator, reduced_divisor)), "rem_biguint failed with numerator: {}, divisor: {}", numerator, divisor);
+
+                    let (reduced_div, reduced_rem) = $struct_name::div_rem_biguint(&numerator, reduced_divisor);
+                    assert_eq!(expected_div, reduced_div, "div_rem_biguint divide failed with numerator: {}, divisor: {}", numerator, divisor);
+                    assert_eq!(expected_rem, BigUint::from(reduced_rem), "div_rem_biguint modulo failed with numerator: {}, divisor: {}", numerator, divisor);
+
+                    assert_eq!(expected_rem, numerator.clone() % reduced_divisor, "By-value modulo failed with numerator: {}, divisor: {}", numerator, divisor);
+                    assert_eq!(expected_div, numerator / reduced_divisor, "By-value divide failed with divisor: {}", divisor);
+                }
+            }
+        }
+    )
+}
+
+#[cfg(feature = "num-bigint")]
+biguint_division_proptest!(strength_reduced_u32_biguint, StrengthReducedU32, u32);
+#[cfg(feature = "num-bigint")]
+biguint_division_proptest!(strength_reduced_u64_biguint, StrengthReducedU64, u64);
+
+// The reduced divisors can stand in for primitive ones in code that's generic over the divisor, because both give the same output types
+#[cfg(feature = "num-bigint")]
+#[test]
+fn test_biguint_operators_match_primitive_output_types() {
+    use std::ops::{Div, Rem};
+
+    fn div_rem_generic<D: Copy>(numerator: &BigUint, divisor: D) -> (BigUint, BigUint) where for<'a> &'a BigUint: Div<D, Output = BigUint> + Rem<D, Output = BigUint> {
+        (numerator / divisor, numerator % divisor)
+    }
+
+    let numerator = BigUint::new(vec![0x12345678, 0x9abcdef0, 0x0fedcba9, 0x87654321]);
+    assert_eq!(div_rem_generic(&numerator, 1000u32), div_rem_generic(&numerator, StrengthReducedU32::new(1000)));
+    assert_eq!(div_rem_generic(&numerator, 1000u64), div_rem_generic(&numerator, StrengthReducedU64::new(1000)));
+}
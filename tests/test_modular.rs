@@ -0,0 +1,78 @@
+#[macro_use]
+extern crate proptest;
+extern crate strength_reduce;
+extern crate num_bigint;
+
+use num_bigint::BigUint;
+use proptest::test_runner::Config;
+use strength_reduce::{ModularU32, ModularU64, DivisorError};
+
+macro_rules! modular_proptest {
+    ($test_name:ident, $struct_name:ident, $primitive_type:ident) => (
+        mod $test_name {
+            use super::*;
+            use proptest::sample::select;
+
+            fn assert_modular_equivalence(modulus: $primitive_type, a: $primitive_type, b: $primitive_type) {
+                let modular = $struct_name::new(modulus);
+                let big_modulus = BigUint::from(modulus);
+                let big_a = BigUint::from(a);
+                let big_b = BigUint::from(b);
+                let reduced_a = &big_a % &big_modulus;
+                let reduced_b = &big_b % &big_modulus;
+
+                let expected_add = (&big_a + &big_b) % &big_modulus;
+                let expected_sub = (&reduced_a + &big_modulus - &reduced_b) % &big_modulus;
+                let expected_neg = (&big_modulus - &reduced_a) % &big_modulus;
+                let expected_mul = (&big_a * &big_b) % &big_modulus;
+                let expected_pow = big_a.modpow(&big_b, &big_modulus);
+
+                assert_eq!(expected_add, BigUint::from(modular.add_mod(a, b)), "add_mod failed with a: {}, b: {}, modulus: {}", a, b, modulus);
+                assert_eq!(expected_sub, BigUint::from(modular.sub_mod(a, b)), "sub_mod failed with a: {}, b: {}, modulus: {}", a, b, modulus);
+                assert_eq!(expected_neg, BigUint::from(modular.neg_mod(a)), "neg_mod failed with a: {}, modulus: {}", a, modulus);
+                assert_eq!(expected_mul, BigUint::from(modular.mul_mod(a, b)), "mul_mod failed with a: {}, b: {}, modulus: {}", a, b, modulus);
+                assert_eq!(expected_pow, BigUint::from(modular.pow_mod(a, b)), "pow_mod failed with base: {}, exponent: {}, modulus: {}", a, b, modulus);
+            }
+
+            #[test]
+            fn test_zero_modulus() {
+                assert_eq!($struct_name::try_new(0), Err(DivisorError::Zero));
+                assert_eq!($struct_name::try_new(7).unwrap().modulus(), 7);
+            }
+
+            proptest! {
+                #![proptest_config(Config::with_cases(100_000))]
+
+                #[test]
+                fn fully_generated_inputs_are_modular_equivalent(modulus in 1..$primitive_type::MAX, a in 0..$primitive_type::MAX, b in 0..$primitive_type::MAX) {
+                    assert_modular_equivalence(modulus, a, b);
+                }
+
+                #[test]
+                fn generated_inputs_with_edge_case_moduli_are_modular_equivalent(
+                        modulus in select(vec![1 as $primitive_type, 2 as $primitive_type, 3 as $primitive_type, $primitive_type::MAX / 2, $primitive_type::MAX / 2 + 1, $primitive_type::MAX - 1, $primitive_type::MAX]),
+                        a in 0..$primitive_type::MAX,
+                        b in 0..$primitive_type::MAX) {
+                    assert_modular_equivalence(modulus, a, b);
+                }
+
+                #[test]
+                fn generated_moduli_with_edge_case_inputs_are_modular_equivalent(
+                        modulus in 1..$primitive_type::MAX,
+                        a in select(vec![0 as $primitive_type, 1 as $primitive_type, $primitive_type::MAX - 1, $primitive_type::MAX]),
+                        b in select(vec![0 as $primitive_type, 1 as $primitive_type, 2 as $primitive_type, $primitive_type::MAX - 1, $primitive_type::MAX])) {
+                    assert_modular_equivalence(modulus, a, b);
+                }
+
+                // the largest reduced inputs are the ones whose sums overflow and whose products need every bit of the wide type
+                #[test]
+                fn generated_reduced_inputs_are_modular_equivalent(modulus in 1..$primitive_type::MAX, a in 0..$primitive_type::MAX, b in 0..$primitive_type::MAX) {
+                    assert_modular_equivalence(modulus, modulus - 1 - a % modulus, modulus - 1 - b % modulus);
+                }
+            }
+        }
+    )
+}
+
+modular_proptest!(modular_u32, ModularU32, u32);
+modular_proptest!(modular_u64, ModularU64, u64);
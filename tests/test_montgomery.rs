@@ -0,0 +1,81 @@
+#[macro_use]
+extern crate proptest;
+extern crate strength_reduce;
+extern crate num_bigint;
+
+use num_bigint::BigUint;
+use proptest::test_runner::Config;
+use strength_reduce::{Montgomery32, Montgomery64, DivisorError};
+
+macro_rules! montgomery_proptest {
+    ($test_name:ident, $struct_name:ident, $primitive_type:ident) => (
+        mod $test_name {
+            use super::*;
+            use proptest::sample::select;
+
+            fn assert_montgomery_equivalence(modulus: $primitive_type, a: $primitive_type, b: $primitive_type) {
+                let montgomery = $struct_name::new(modulus);
+                let big_modulus = BigUint::from(modulus);
+                let big_a = BigUint::from(a);
+                let big_b = BigUint::from(b);
+                let r = BigUint::from(1u8) << $primitive_type::BITS;
+
+                let montgomery_a = montgomery.to_montgomery(a);
+                let montgomery_b = montgomery.to_montgomery(b);
+                assert_eq!((&big_a * &r) % &big_modulus, BigUint::from(montgomery_a), "to_montgomery failed with value: {}, modulus: {}", a, modulus);
+                assert_eq!(&big_a % &big_modulus, BigUint::from(montgomery.from_montgomery(montgomery_a)), "from_montgomery failed with value: {}, modulus: {}", a, modulus);
+                assert_eq!(&r % &big_modulus, BigUint::from(montgomery.one()), "one failed with modulus: {}", modulus);
+
+                let expected_mul = (&big_a * &big_b) % &big_modulus;
+                let expected_square = (&big_a * &big_a) % &big_modulus;
+                let expected_pow = big_a.modpow(&big_b, &big_modulus);
+
+                assert_eq!(expected_mul, BigUint::from(montgomery.from_montgomery(montgomery.mul(montgomery_a, montgomery_b))), "mul failed with a: {}, b: {}, modulus: {}", a, b, modulus);
+                assert_eq!(expected_square, BigUint::from(montgomery.from_montgomery(montgomery.square(montgomery_a))), "square failed with a: {}, modulus: {}", a, modulus);
+                assert_eq!(expected_pow, BigUint::from(montgomery.from_montgomery(montgomery.pow(montgomery_a, b))), "pow failed with base: {}, exponent: {}, modulus: {}", a, b, modulus);
+            }
+
+            #[test]
+            fn test_invalid_modulus() {
+                assert_eq!($struct_name::try_new(0), Err(DivisorError::Zero));
+                assert_eq!($struct_name::try_new(2), Err(DivisorError::Even));
+                assert_eq!($struct_name::try_new($primitive_type::MAX - 1), Err(DivisorError::Even));
+                assert_eq!($struct_name::try_new(7).unwrap().modulus(), 7);
+            }
+
+            #[test]
+            #[should_panic]
+            fn test_even_modulus_panics() {
+                $struct_name::new(10);
+            }
+
+            proptest! {
+                #![proptest_config(Config::with_cases(100_000))]
+
+                #[test]
+                fn fully_generated_inputs_are_montgomery_equivalent(modulus in 0..$primitive_type::MAX / 2, a in 0..$primitive_type::MAX, b in 0..$primitive_type::MAX) {
+                    assert_montgomery_equivalence(modulus * 2 + 1, a, b);
+                }
+
+                #[test]
+                fn generated_inputs_with_edge_case_moduli_are_montgomery_equivalent(
+                        modulus in select(vec![1 as $primitive_type, 3 as $primitive_type, 5 as $primitive_type, $primitive_type::MAX / 2, $primitive_type::MAX / 2 + 2, $primitive_type::MAX - 2, $primitive_type::MAX]),
+                        a in 0..$primitive_type::MAX,
+                        b in 0..$primitive_type::MAX) {
+                    assert_montgomery_equivalence(modulus, a, b);
+                }
+
+                #[test]
+                fn generated_moduli_with_edge_case_inputs_are_montgomery_equivalent(
+                        modulus in 0..$primitive_type::MAX / 2,
+                        a in select(vec![0 as $primitive_type, 1 as $primitive_type, $primitive_type::MAX - 1, $primitive_type::MAX]),
+                        b in select(vec![0 as $primitive_type, 1 as $primitive_type, 2 as $primitive_type, $primitive_type::MAX - 1, $primitive_type::MAX])) {
+                    assert_montgomery_equivalence(modulus * 2 + 1, a, b);
+                }
+            }
+        }
+    )
+}
+
+montgomery_proptest!(montgomery_32, Montgomery32, u32);
+montgomery_proptest!(montgomery_64, Montgomery64, u64);
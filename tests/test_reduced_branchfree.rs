@@ -0,0 +1,136 @@
+#[macro_use]
+extern crate proptest;
+extern crate strength_reduce;
+
+use proptest::test_runner::Config;
+use strength_reduce::{StrengthReducedBranchfreeU32, StrengthReducedBranchfreeU64};
+
+
+macro_rules! branchfree_reduction_proptest {
+    ($test_name:ident, $struct_name:ident, $primitive_type:ident) => (
+        mod $test_name {
+            use super::*;
+            use proptest::sample::select;
+            use proptest::collection::vec;
+
+            fn assert_div_rem_equivalence(divisor: $primitive_type, numerator: $primitive_type) {
+                let reduced_divisor = $struct_name::new(divisor);
+                let expected_div = numerator / divisor;
+                let expected_rem = numerator % divisor;
+                let reduced_div = numerator / reduced_divisor;
+                let reduced_rem = numerator % reduced_divisor;
+                assert_eq!(expected_div, reduced_div, "Divide failed with numerator: {}, divisor: {}", numerator, divisor);
+                assert_eq!(expected_rem, reduced_rem, "Modulo failed with numerator: {}, divisor: {}", numerator, divisor);
+                let (reduced_combined_div, reduced_combined_rem) = $struct_name::div_rem(numerator, reduced_divisor);
+                assert_eq!(expected_div, reduced_combined_div, "div_rem divide failed with numerator: {}, divisor: {}", numerator, divisor);
+                assert_eq!(expected_rem, reduced_combined_rem, "div_rem modulo failed with numerator: {}, divisor: {}", numerator, divisor);
+                let expected_ceil = expected_div + if expected_rem > 0 { 1 } else { 0 };
+                let expected_round = expected_div + if expected_rem >= divisor - expected_rem { 1 } else { 0 };
+                assert_eq!(expected_div, $struct_name::div_floor(numerator, reduced_divisor), "div_floor failed with numerator: {}, divisor: {}", numerator, divisor);
+                assert_eq!(expected_ceil, $struct_name::div_ceil(numerator, reduced_divisor), "div_ceil failed with numerator: {}, divisor: {}", numerator, divisor);
+                assert_eq!(expected_round, $struct_name::div_round(numerator, reduced_divisor), "div_round failed with numerator: {}, divisor: {}", numerator, divisor);
+
+                assert_eq!(expected_rem == 0, $struct_name::is_multiple_of(numerator, reduced_divisor), "is_multiple_of failed with numerator: {}, divisor: {}", numerator, divisor);
+                assert_eq!(expected_rem == 0, reduced_divisor.divides(numerator), "divides failed with numerator: {}, divisor: {}", numerator, divisor);
+                let reduced_checked_exact = $struct_name::checked_divide_exact(numerator, reduced_divisor);
+                if expected_rem == 0 {
+                    assert_eq!(Some(expected_div), reduced_checked_exact, "checked_divide_exact failed with numerator: {}, divisor: {}", numerator, divisor);
+                    assert_eq!(expected_div, $struct_name::divide_exact(numerator, reduced_divisor), "divide_exact failed with numerator: {}, divisor: {}", numerator, divisor);
+                } else {
+                    assert_eq!(None, reduced_checked_exact, "checked_divide_exact failed with numerator: {}, divisor: {}", numerator, divisor);
+                }
+            }
+
+            fn assert_slice_equivalence(divisor: $primitive_type, numerators: &[$primitive_type]) {
+                let reduced_divisor = $struct_name::new(divisor);
+                let expected_div: Vec<$primitive_type> = numerators.iter().map(|&numerator| numerator / divisor).collect();
+                let expected_rem: Vec<$primitive_type> = numerators.iter().map(|&numerator| numerator % divisor).collect();
+
+                let mut quotients = vec![0; numerators.len()];
+                let mut remainders = vec![0; numerators.len()];
+                $struct_name::div_slice(numerators, reduced_divisor, &mut quotients);
+                assert_eq!(expected_div, quotients, "div_slice failed with divisor: {}", divisor);
+                $struct_name::rem_slice(numerators, reduced_divisor, &mut remainders);
+                assert_eq!(expected_rem, remainders, "rem_slice failed with divisor: {}", divisor);
+
+                let mut quotients = vec![0; numerators.len()];
+                let mut remainders = vec![0; numerators.len()];
+                $struct_name::div_rem_slice(numerators, reduced_divisor, &mut quotients, &mut remainders);
+                assert_eq!(expected_div, quotients, "div_rem_slice divide failed with divisor: {}", divisor);
+                assert_eq!(expected_rem, remainders, "div_rem_slice modulo failed with divisor: {}", divisor);
+
+                let mut values = numerators.to_vec();
+                $struct_name::div_slice_in_place(&mut values, reduced_divisor);
+                assert_eq!(expected_div, values, "div_slice_in_place failed with divisor: {}", divisor);
+
+                let mut values = numerators.to_vec();
+                $struct_name::rem_slice_in_place(&mut values, reduced_divisor);
+                assert_eq!(expected_rem, values, "rem_slice_in_place failed with divisor: {}", divisor);
+            }
+
+            proptest! {
+                #![proptest_config(Config::with_cases(100_000))]
+
+                #[test]
+                fn fully_generated_inputs_are_div_rem_equivalent(divisor in 1..$primitive_type::MAX, numerator in 0..$primitive_type::MAX) {
+                    assert_div_rem_equivalence(divisor, numerator);
+                }
+
+                #[test]
+                fn generated_divisors_with_edge_case_numerators_are_div_rem_equivalent(
+                        divisor in 1..$primitive_type::MAX,
+                        numerator in select(vec![0 as $primitive_type, 1 as $primitive_type, $primitive_type::MAX - 1, $primitive_type::MAX])) {
+                    assert_div_rem_equivalence(divisor, numerator);
+                }
+
+                #[test]
+                fn generated_numerators_with_edge_case_divisors_are_div_rem_equivalent(
+                        divisor in select(vec![1 as $primitive_type, 2 as $primitive_type, 3 as $primitive_type, $primitive_type::MAX / 2, $primitive_type::MAX / 2 + 1, $primitive_type::MAX - 1, $primitive_type::MAX]),
+                        numerator in 0..$primitive_type::MAX) {
+                    assert_div_rem_equivalence(divisor, numerator);
+                }
+
+                // powers of two go through the same arithmetic as every other divisor, so they get their own test
+                #[test]
+                fn generated_powers_of_two_are_div_rem_equivalent(exponent in 0..$primitive_type::MAX.count_ones(), numerator in 0..$primitive_type::MAX) {
+                    assert_div_rem_equivalence(1 << exponent, numerator);
+                }
+
+                #[test]
+                fn generated_multiples_are_div_rem_equivalent(divisor in 1..$primitive_type::MAX, numerator in 0..$primitive_type::MAX) {
+                    // round the numerator down to a multiple of the divisor, so that we exercise the "is a multiple" side of is_multiple_of
+                    assert_div_rem_equivalence(divisor, numerator / divisor * divisor);
+                }
+            }
+
+            proptest! {
+                #![proptest_config(Config::with_cases(1_000))]
+
+                // the use case these types are for: a different divisor for every numerator
+                #[test]
+                fn generated_divisor_per_numerator_is_div_rem_equivalent(pairs in vec((1..$primitive_type::MAX, 0..$primitive_type::MAX), 0..100)) {
+                    let reduced_divisors: Vec<$struct_name> = pairs.iter().map(|&(divisor, _)| $struct_name::new(divisor)).collect();
+                    for (&(divisor, numerator), &reduced_divisor) in pairs.iter().zip(&reduced_divisors) {
+                        assert_eq!(numerator / divisor, numerator / reduced_divisor, "Divide failed with numerator: {}, divisor: {}", numerator, divisor);
+                        assert_eq!(numerator % divisor, numerator % reduced_divisor, "Modulo failed with numerator: {}, divisor: {}", numerator, divisor);
+                    }
+                }
+
+                #[test]
+                fn generated_slices_are_div_rem_equivalent(divisor in 1..$primitive_type::MAX, numerators in vec(0..$primitive_type::MAX, 0..100)) {
+                    assert_slice_equivalence(divisor, &numerators);
+                }
+
+                #[test]
+                fn generated_slices_with_edge_case_divisors_are_div_rem_equivalent(
+                        divisor in select(vec![1 as $primitive_type, 2 as $primitive_type, 8 as $primitive_type, $primitive_type::MAX - 1, $primitive_type::MAX]),
+                        numerators in vec(0..$primitive_type::MAX, 0..100)) {
+                    assert_slice_equivalence(divisor, &numerators);
+                }
+            }
+        }
+    )
+}
+
+branchfree_reduction_proptest!(strength_reduced_branchfree_u32, StrengthReducedBranchfreeU32, u32);
+branchfree_reduction_proptest!(strength_reduced_branchfree_u64, StrengthReducedBranchfreeU64, u64);
@@ -0,0 +1,113 @@
+#[macro_use]
+extern crate proptest;
+extern crate strength_reduce;
+
+use proptest::test_runner::Config;
+use strength_reduce::{StrengthReducedI8, StrengthReducedI16, StrengthReducedI32, StrengthReducedI64, StrengthReducedIsize, StrengthReducedI128};
+
+
+macro_rules! signed_reduction_proptest {
+    ($test_name:ident, $struct_name:ident, $primitive_type:ident) => (
+        mod $test_name {
+            use super::*;
+            use proptest::sample::select;
+
+            fn assert_div_rem_equivalence(divisor: $primitive_type, numerator: $primitive_type) {
+                let reduced_divisor = $struct_name::new(divisor);
+                let expected_div = numerator / divisor;
+                let expected_rem = numerator % divisor;
+                let reduced_div = numerator / reduced_divisor;
+                let reduced_rem = numerator % reduced_divisor;
+                assert_eq!(expected_div, reduced_div, "Divide failed with numerator: {}, divisor: {}", numerator, divisor);
+                assert_eq!(expected_rem, reduced_rem, "Modulo failed with numerator: {}, divisor: {}", numerator, divisor);
+                let (reduced_combined_div, reduced_combined_rem) = $struct_name::div_rem(numerator, reduced_divisor);
+                assert_eq!(expected_div, reduced_combined_div, "div_rem divide failed with numerator: {}, divisor: {}", numerator, divisor);
+                assert_eq!(expected_rem, reduced_combined_rem, "div_rem modulo failed with numerator: {}, divisor: {}", numerator, divisor);
+
+                let inexact = expected_rem != 0;
+                let negative = (numerator < 0) != (divisor < 0);
+                let expected_floor = if inexact && negative { expected_div - 1 } else { expected_div };
+                let expected_ceil = if inexact && !negative { expected_div + 1 } else { expected_div };
+                let rounds_away = inexact && expected_rem.unsigned_abs() >= divisor.unsigned_abs() - expected_rem.unsigned_abs();
+                let expected_round = if !rounds_away { expected_div } else if negative { expected_div - 1 } else { expected_div + 1 };
+                assert_eq!(expected_floor, $struct_name::div_floor(numerator, reduced_divisor), "div_floor failed with numerator: {}, divisor: {}", numerator, divisor);
+                assert_eq!(expected_ceil, $struct_name::div_ceil(numerator, reduced_divisor), "div_ceil failed with numerator: {}, divisor: {}", numerator, divisor);
+                assert_eq!(expected_round, $struct_name::div_round(numerator, reduced_divisor), "div_round failed with numerator: {}, divisor: {}", numerator, divisor);
+                assert_eq!(numerator.div_euclid(divisor), $struct_name::div_euclid(numerator, reduced_divisor), "div_euclid failed with numerator: {}, divisor: {}", numerator, divisor);
+                assert_eq!(numerator.rem_euclid(divisor), $struct_name::rem_euclid(numerator, reduced_divisor), "rem_euclid failed with numerator: {}, divisor: {}", numerator, divisor);
+            }
+
+
+
+            proptest! {
+                #![proptest_config(Config::with_cases(100_000))]
+
+                #[test]
+                fn fully_generated_inputs_are_div_rem_equivalent(divisor in $primitive_type::MIN..$primitive_type::MAX, numerator in $primitive_type::MIN..$primitive_type::MAX) {
+                    prop_assume!(divisor != 0);
+                    prop_assume!(!(numerator == $primitive_type::MIN && divisor == -1));
+                    assert_div_rem_equivalence(divisor, numerator);
+                }
+
+                #[test]
+                fn generated_divisors_with_edge_case_numerators_are_div_rem_equivalent(
+                        divisor in $primitive_type::MIN..$primitive_type::MAX,
+                        numerator in select(vec![0 as $primitive_type, 1 as $primitive_type, -1 as $primitive_type, $primitive_type::MAX - 1, $primitive_type::MAX, $primitive_type::MIN + 1, $primitive_type::MIN])) {
+                    prop_assume!(divisor != 0);
+                    prop_assume!(!(numerator == $primitive_type::MIN && divisor == -1));
+                    assert_div_rem_equivalence(divisor, numerator);
+                }
+
+                #[test]
+                fn generated_numerators_with_edge_case_divisors_are_div_rem_equivalent(
+                        divisor in select(vec![1 as $primitive_type, 2 as $primitive_type, -1 as $primitive_type, -2 as $primitive_type, $primitive_type::MAX - 1, $primitive_type::MAX, $primitive_type::MIN + 1, $primitive_type::MIN]),
+                        numerator in $primitive_type::MIN..$primitive_type::MAX) {
+                    prop_assume!(!(numerator == $primitive_type::MIN && divisor == -1));
+                    assert_div_rem_equivalence(divisor, numerator);
+                }
+            }
+        }
+    )
+}
+signed_reduction_proptest!(strength_reduced_i08, StrengthReducedI8, i8);
+signed_reduction_proptest!(strength_reduced_i16, StrengthReducedI16, i16);
+signed_reduction_proptest!(strength_reduced_i32, StrengthReducedI32, i32);
+signed_reduction_proptest!(strength_reduced_i64, StrengthReducedI64, i64);
+signed_reduction_proptest!(strength_reduced_isize, StrengthReducedIsize, isize);
+signed_reduction_proptest!(strength_reduced_i128, StrengthReducedI128, i128);
+
+macro_rules! signed_exhaustive_test {
+    ($test_name:ident, $struct_name:ident, $primitive_type:ident) => (
+    	#[test]
+    	#[ignore]
+    	fn $test_name() {
+    		for divisor in $primitive_type::MIN..=$primitive_type::MAX {
+    			if divisor == 0 {
+    				continue;
+    			}
+    			let reduced_divisor = $struct_name::new(divisor);
+
+    			for numerator in $primitive_type::MIN..=$primitive_type::MAX {
+    				if numerator == $primitive_type::MIN && divisor == -1 {
+    					continue;
+    				}
+    				let expected_div = numerator / divisor;
+	                let expected_rem = numerator % divisor;
+
+	                let reduced_div = numerator / reduced_divisor;
+	                assert_eq!(expected_div, reduced_div, "Divide failed with numerator: {}, divisor: {}", numerator, divisor);
+
+	                let reduced_rem = numerator % reduced_divisor;
+	                assert_eq!(expected_rem, reduced_rem, "Modulo failed with numerator: {}, divisor: {}", numerator, divisor);
+
+	                let (reduced_combined_div, reduced_combined_rem) = $struct_name::div_rem(numerator, reduced_divisor);
+	                assert_eq!(expected_div, reduced_combined_div, "div_rem divide failed with numerator: {}, divisor: {}", numerator, divisor);
+	                assert_eq!(expected_rem, reduced_combined_rem, "div_rem modulo failed with numerator: {}, divisor: {}", numerator, divisor);
+    			}
+    		}
+    	}
+    )
+}
+
+signed_exhaustive_test!(test_strength_reduced_i08_exhaustive, StrengthReducedI8, i8);
+signed_exhaustive_test!(test_strength_reduced_i16_exhaustive, StrengthReducedI16, i16);
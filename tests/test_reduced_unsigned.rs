@@ -11,6 +11,7 @@ macro_rules! reduction_proptest {
         mod $test_name {
             use super::*;
             use proptest::sample::select;
+            use proptest::collection::vec;
 
             fn assert_div_rem_equivalence(divisor: $primitive_type, numerator: $primitive_type) {
                 let reduced_divisor = $struct_name::new(divisor);
@@ -23,6 +24,22 @@ macro_rules! reduction_proptest {
                 let (reduced_combined_div, reduced_combined_rem) = $struct_name::div_rem(numerator, reduced_divisor);
                 assert_eq!(expected_div, reduced_combined_div, "div_rem divide failed with numerator: {}, divisor: {}", numerator, divisor);
                 assert_eq!(expected_rem, reduced_combined_rem, "div_rem modulo failed with numerator: {}, divisor: {}", numerator, divisor);
+                let expected_ceil = expected_div + if expected_rem > 0 { 1 } else { 0 };
+                let expected_round = expected_div + if expected_rem >= divisor - expected_rem { 1 } else { 0 };
+                assert_eq!(expected_div, $struct_name::div_floor(numerator, reduced_divisor), "div_floor failed with numerator: {}, divisor: {}", numerator, divisor);
+                assert_eq!(expected_ceil, $struct_name::div_ceil(numerator, reduced_divisor), "div_ceil failed with numerator: {}, divisor: {}", numerator, divisor);
+                assert_eq!(expected_round, $struct_name::div_round(numerator, reduced_divisor), "div_round failed with numerator: {}, divisor: {}", numerator, divisor);
+                assert_eq!(numerator.div_euclid(divisor), $struct_name::div_euclid(numerator, reduced_divisor), "div_euclid failed with numerator: {}, divisor: {}", numerator, divisor);
+                assert_eq!(numerator.rem_euclid(divisor), $struct_name::rem_euclid(numerator, reduced_divisor), "rem_euclid failed with numerator: {}, divisor: {}", numerator, divisor);
+                let reduced_is_multiple = $struct_name::is_multiple_of(numerator, reduced_divisor);
+                assert_eq!(expected_rem == 0, reduced_is_multiple, "is_multiple_of failed with numerator: {}, divisor: {}", numerator, divisor);
+                let reduced_checked_exact = $struct_name::checked_divide_exact(numerator, reduced_divisor);
+                if expected_rem == 0 {
+                    assert_eq!(Some(expected_div), reduced_checked_exact, "checked_divide_exact failed with numerator: {}, divisor: {}", numerator, divisor);
+                    assert_eq!(expected_div, $struct_name::divide_exact(numerator, reduced_divisor), "divide_exact failed with numerator: {}, divisor: {}", numerator, divisor);
+                } else {
+                    assert_eq!(None, reduced_checked_exact, "checked_divide_exact failed with numerator: {}, divisor: {}", numerator, divisor);
+                }
             }
 
 
@@ -31,23 +48,72 @@ macro_rules! reduction_proptest {
                 #![proptest_config(Config::with_cases(100_000))]
 
                 #[test]
-                fn fully_generated_inputs_are_div_rem_equivalent(divisor in 1..core::$primitive_type::MAX, numerator in 0..core::$primitive_type::MAX) {
+                fn fully_generated_inputs_are_div_rem_equivalent(divisor in 1..$primitive_type::MAX, numerator in 0..$primitive_type::MAX) {
                     assert_div_rem_equivalence(divisor, numerator);
                 }
 
                 #[test]
                 fn generated_divisors_with_edge_case_numerators_are_div_rem_equivalent(
-                        divisor in 1..core::$primitive_type::MAX,
-                        numerator in select(vec![0 as $primitive_type, 1 as $primitive_type, core::$primitive_type::MAX - 1, core::$primitive_type::MAX])) {
+                        divisor in 1..$primitive_type::MAX,
+                        numerator in select(vec![0 as $primitive_type, 1 as $primitive_type, $primitive_type::MAX - 1, $primitive_type::MAX])) {
                     assert_div_rem_equivalence(divisor, numerator);
                 }
 
                 #[test]
                 fn generated_numerators_with_edge_case_divisors_are_div_rem_equivalent(
-                        divisor in select(vec![1 as $primitive_type, 2 as $primitive_type, core::$primitive_type::MAX - 1, core::$primitive_type::MAX]),
-                        numerator in 0..core::$primitive_type::MAX) {
+                        divisor in select(vec![1 as $primitive_type, 2 as $primitive_type, $primitive_type::MAX - 1, $primitive_type::MAX]),
+                        numerator in 0..$primitive_type::MAX) {
                     assert_div_rem_equivalence(divisor, numerator);
                 }
+
+                #[test]
+                fn generated_multiples_are_div_rem_equivalent(divisor in 1..$primitive_type::MAX, numerator in 0..$primitive_type::MAX) {
+                    // round the numerator down to a multiple of the divisor, so that we exercise the "is a multiple" side of is_multiple_of
+                    assert_div_rem_equivalence(divisor, numerator / divisor * divisor);
+                }
+            }
+
+            fn assert_slice_equivalence(divisor: $primitive_type, numerators: &[$primitive_type]) {
+                let reduced_divisor = $struct_name::new(divisor);
+                let expected_div: Vec<$primitive_type> = numerators.iter().map(|&numerator| numerator / divisor).collect();
+                let expected_rem: Vec<$primitive_type> = numerators.iter().map(|&numerator| numerator % divisor).collect();
+
+                let mut quotients = vec![0; numerators.len()];
+                let mut remainders = vec![0; numerators.len()];
+                $struct_name::div_slice(numerators, reduced_divisor, &mut quotients);
+                assert_eq!(expected_div, quotients, "div_slice failed with divisor: {}", divisor);
+                $struct_name::rem_slice(numerators, reduced_divisor, &mut remainders);
+                assert_eq!(expected_rem, remainders, "rem_slice failed with divisor: {}", divisor);
+
+                let mut quotients = vec![0; numerators.len()];
+                let mut remainders = vec![0; numerators.len()];
+                $struct_name::div_rem_slice(numerators, reduced_divisor, &mut quotients, &mut remainders);
+                assert_eq!(expected_div, quotients, "div_rem_slice divide failed with divisor: {}", divisor);
+                assert_eq!(expected_rem, remainders, "div_rem_slice modulo failed with divisor: {}", divisor);
+
+                let mut values = numerators.to_vec();
+                $struct_name::div_slice_in_place(&mut values, reduced_divisor);
+                assert_eq!(expected_div, values, "div_slice_in_place failed with divisor: {}", divisor);
+
+                let mut values = numerators.to_vec();
+                $struct_name::rem_slice_in_place(&mut values, reduced_divisor);
+                assert_eq!(expected_rem, values, "rem_slice_in_place failed with divisor: {}", divisor);
+            }
+
+            proptest! {
+                #![proptest_config(Config::with_cases(1_000))]
+
+                #[test]
+                fn generated_slices_are_div_rem_equivalent(divisor in 1..$primitive_type::MAX, numerators in vec(0..$primitive_type::MAX, 0..100)) {
+                    assert_slice_equivalence(divisor, &numerators);
+                }
+
+                #[test]
+                fn generated_slices_with_edge_case_divisors_are_div_rem_equivalent(
+                        divisor in select(vec![1 as $primitive_type, 2 as $primitive_type, 8 as $primitive_type, $primitive_type::MAX - 1, $primitive_type::MAX]),
+                        numerators in vec(0..$primitive_type::MAX, 0..100)) {
+                    assert_slice_equivalence(divisor, &numerators);
+                }
             }
         }
     )
@@ -59,15 +125,151 @@ reduction_proptest!(strength_reduced_u64, StrengthReducedU64, u64);
 reduction_proptest!(strength_reduced_usize, StrengthReducedUsize, usize);
 reduction_proptest!(strength_reduced_u128, StrengthReducedU128, u128);
 
+// StrengthReducedU128 divides numerators and divisors that both fit in 64 bits with a separate 64-bit multiplier,
+// but fully generated u128s almost never fit in 64 bits, so these generate values of every bit length, on both sides of that boundary
+mod strength_reduced_u128_mixed_magnitudes {
+    use super::*;
+    use proptest::collection::vec;
+    use proptest::prelude::any;
+
+    fn truncate_to_bits(value: u128, bits: u32) -> u128 {
+        value.checked_shr(128 - bits).unwrap_or(0)
+    }
+
+    fn assert_div_rem_equivalence(divisor: u128, numerator: u128) {
+        let reduced_divisor = StrengthReducedU128::new(divisor);
+        let expected_div = numerator / divisor;
+        let expected_rem = numerator % divisor;
+        assert_eq!(expected_div, numerator / reduced_divisor, "Divide failed with numerator: {}, divisor: {}", numerator, divisor);
+        assert_eq!(expected_rem, numerator % reduced_divisor, "Modulo failed with numerator: {}, divisor: {}", numerator, divisor);
+        assert_eq!((expected_div, expected_rem), StrengthReducedU128::div_rem(numerator, reduced_divisor), "div_rem failed with numerator: {}, divisor: {}", numerator, divisor);
+    }
+
+    #[test]
+    fn test_edge_case_magnitudes() {
+        // 64-bit numerators and divisors, including two cases that broke earlier versions of the shortcut
+        assert_div_rem_equivalence(3, 1749117402913138231);
+        assert_div_rem_equivalence(5, 177238643);
+        assert_div_rem_equivalence(u64::MAX as u128, u64::MAX as u128);
+        assert_div_rem_equivalence(u64::MAX as u128 - 1, u64::MAX as u128);
+
+        // generated_bit_lengths caught a broken zero-quotient shortcut with divisor_bits = 65, divisor = 2^63 and a numerator of 0,
+        // which it turns into the divisor 2^64 + 1. Both divisors are pinned here
+        assert_div_rem_equivalence(1 << 63, 0);
+        assert_div_rem_equivalence((1 << 64) + 1, 0);
+
+        // 64-bit numerators with divisors that don't fit in 64 bits, which always have a quotient of 0
+        let numerators = [0, 1, 6, u64::MAX as u128 - 1, u64::MAX as u128];
+        let divisors = [u64::MAX as u128 + 2, u64::MAX as u128 * 3, u128::MAX / 6, u128::MAX - 1, u128::MAX];
+        for &divisor in &divisors {
+            for &numerator in &numerators {
+                assert_div_rem_equivalence(divisor, numerator);
+            }
+        }
+    }
+
+    proptest! {
+        #![proptest_config(Config::with_cases(100_000))]
+
+        #[test]
+        fn generated_64bit_inputs_are_div_rem_equivalent(divisor in 1..=u64::MAX, numerator in 0..=u64::MAX) {
+            assert_div_rem_equivalence(divisor as u128, numerator as u128);
+        }
+
+        #[test]
+        fn generated_bit_lengths_are_div_rem_equivalent(divisor_bits in 1u32..=128, divisor in any::<u128>(), numerator_bits in 0u32..=128, numerator in any::<u128>()) {
+            // the top bit of the divisor is forced on, so that it's never zero and has exactly divisor_bits bits
+            let divisor = truncate_to_bits(divisor, divisor_bits) | (1 << (divisor_bits - 1));
+            assert_div_rem_equivalence(divisor, truncate_to_bits(numerator, numerator_bits));
+        }
+    }
+
+    proptest! {
+        #![proptest_config(Config::with_cases(1_000))]
+
+        #[test]
+        fn generated_slices_with_mixed_numerators_are_div_rem_equivalent(divisor in 1..=u64::MAX, numerators in vec((0u32..=128, any::<u128>()), 0..100)) {
+            let numerators: Vec<u128> = numerators.into_iter().map(|(bits, numerator)| truncate_to_bits(numerator, bits)).collect();
+            let reduced_divisor = StrengthReducedU128::new(divisor as u128);
+
+            let mut quotients = vec![0; numerators.len()];
+            let mut remainders = vec![0; numerators.len()];
+            StrengthReducedU128::div_rem_slice(&numerators, reduced_divisor, &mut quotients, &mut remainders);
+            for ((&numerator, &quotient), &remainder) in numerators.iter().zip(&quotients).zip(&remainders) {
+                assert_eq!(numerator / divisor as u128, quotient, "div_rem_slice divide failed with numerator: {}, divisor: {}", numerator, divisor);
+                assert_eq!(numerator % divisor as u128, remainder, "div_rem_slice modulo failed with numerator: {}, divisor: {}", numerator, divisor);
+            }
+        }
+    }
+}
+
+macro_rules! wide_reduction_proptest {
+    ($test_name:ident, $struct_name:ident, $primitive_type:ident, $wide_type:ident) => (
+        mod $test_name {
+            use super::*;
+            use proptest::sample::select;
+
+            fn assert_wide_div_rem_equivalence(divisor: $primitive_type, numerator: $wide_type) {
+                let reduced_divisor = $struct_name::new(divisor);
+                let expected_div = numerator / divisor as $wide_type;
+                let expected_rem = (numerator % divisor as $wide_type) as $primitive_type;
+                let (reduced_div, reduced_rem) = $struct_name::div_rem_wide(numerator, reduced_divisor);
+                assert_eq!(expected_div, reduced_div, "div_rem_wide divide failed with numerator: {}, divisor: {}", numerator, divisor);
+                assert_eq!(expected_rem, reduced_rem, "div_rem_wide modulo failed with numerator: {}, divisor: {}", numerator, divisor);
+
+                let bits = core::mem::size_of::<$primitive_type>() * 8;
+                let numerator_hi = (numerator >> bits) as $primitive_type;
+                if numerator_hi < divisor {
+                    let (narrowing_div, narrowing_rem) = $struct_name::div_rem_narrowing(numerator_hi, numerator as $primitive_type, reduced_divisor);
+                    assert_eq!(expected_div as $primitive_type, narrowing_div, "div_rem_narrowing divide failed with numerator: {}, divisor: {}", numerator, divisor);
+                    assert_eq!(expected_rem, narrowing_rem, "div_rem_narrowing modulo failed with numerator: {}, divisor: {}", numerator, divisor);
+                }
+            }
+
+            proptest! {
+                #![proptest_config(Config::with_cases(100_000))]
+
+                #[test]
+                fn fully_generated_inputs_are_div_rem_equivalent(divisor in 1..$primitive_type::MAX, numerator in 0..$wide_type::MAX) {
+                    assert_wide_div_rem_equivalence(divisor, numerator);
+                }
+
+                #[test]
+                fn generated_narrowing_inputs_are_div_rem_equivalent(divisor in 1..$primitive_type::MAX, numerator_hi in 0..$primitive_type::MAX, numerator_lo in 0..$primitive_type::MAX) {
+                    // keep the upper half smaller than the divisor, so that the quotient fits
+                    let bits = core::mem::size_of::<$primitive_type>() * 8;
+                    assert_wide_div_rem_equivalence(divisor, ((numerator_hi % divisor) as $wide_type) << bits | numerator_lo as $wide_type);
+                }
+
+                #[test]
+                fn generated_divisors_with_edge_case_numerators_are_div_rem_equivalent(
+                        divisor in 1..$primitive_type::MAX,
+                        numerator in select(vec![0 as $wide_type, 1 as $wide_type, $primitive_type::MAX as $wide_type, $primitive_type::MAX as $wide_type + 1, $wide_type::MAX - 1, $wide_type::MAX])) {
+                    assert_wide_div_rem_equivalence(divisor, numerator);
+                }
+
+                #[test]
+                fn generated_numerators_with_edge_case_divisors_are_div_rem_equivalent(
+                        divisor in select(vec![1 as $primitive_type, 2 as $primitive_type, 3 as $primitive_type, $primitive_type::MAX - 1, $primitive_type::MAX]),
+                        numerator in 0..$wide_type::MAX) {
+                    assert_wide_div_rem_equivalence(divisor, numerator);
+                }
+            }
+        }
+    )
+}
+wide_reduction_proptest!(strength_reduced_u32_wide, StrengthReducedU32, u32, u64);
+wide_reduction_proptest!(strength_reduced_u64_wide, StrengthReducedU64, u64, u128);
+
 macro_rules! exhaustive_test {
     ($test_name:ident, $struct_name:ident, $primitive_type:ident) => (
     	#[test]
     	#[ignore]
     	fn $test_name() {
-    		for divisor in 1..=std::$primitive_type::MAX {
+    		for divisor in 1..=$primitive_type::MAX {
     			let reduced_divisor = $struct_name::new(divisor);
 
-    			for numerator in 0..=std::$primitive_type::MAX {
+    			for numerator in 0..=$primitive_type::MAX {
     				let expected_div = numerator / divisor;
 	                let expected_rem = numerator % divisor;
 
@@ -80,6 +282,17 @@ macro_rules! exhaustive_test {
 	                let (reduced_combined_div, reduced_combined_rem) = $struct_name::div_rem(numerator, reduced_divisor);
 	                assert_eq!(expected_div, reduced_combined_div, "div_rem divide failed with numerator: {}, divisor: {}", numerator, divisor);
 	                assert_eq!(expected_rem, reduced_combined_rem, "div_rem modulo failed with numerator: {}, divisor: {}", numerator, divisor);
+
+	                let reduced_is_multiple = $struct_name::is_multiple_of(numerator, reduced_divisor);
+	                assert_eq!(expected_rem == 0, reduced_is_multiple, "is_multiple_of failed with numerator: {}, divisor: {}", numerator, divisor);
+
+	                let reduced_checked_exact = $struct_name::checked_divide_exact(numerator, reduced_divisor);
+	                if expected_rem == 0 {
+	                    assert_eq!(Some(expected_div), reduced_checked_exact, "checked_divide_exact failed with numerator: {}, divisor: {}", numerator, divisor);
+	                    assert_eq!(expected_div, $struct_name::divide_exact(numerator, reduced_divisor), "divide_exact failed with numerator: {}, divisor: {}", numerator, divisor);
+	                } else {
+	                    assert_eq!(None, reduced_checked_exact, "checked_divide_exact failed with numerator: {}, divisor: {}", numerator, divisor);
+	                }
     			}
     		}
     	}
@@ -0,0 +1,111 @@
+#![cfg(feature = "serde")]
+
+#[macro_use]
+extern crate serde_derive;
+extern crate serde;
+extern crate serde_json;
+extern crate strength_reduce;
+
+use serde::Deserialize;
+use strength_reduce::{StrengthReducedU8, StrengthReducedU16, StrengthReducedU32, StrengthReducedU64, StrengthReducedU128, StrengthReducedUsize};
+use strength_reduce::{StrengthReducedI8, StrengthReducedI16, StrengthReducedI32, StrengthReducedI64, StrengthReducedI128, StrengthReducedIsize};
+use strength_reduce::{StrengthReducedBranchfreeU32, StrengthReducedBranchfreeU64};
+use strength_reduce::with_multiplier::WithMultiplier;
+
+// Routes a divisor through with_multiplier, so that its serialized form includes the multiplier
+#[derive(Serialize, Deserialize)]
+struct Multiplied<T: WithMultiplier>(#[serde(with = "strength_reduce::with_multiplier")] T);
+
+fn error_message<T: for<'de> Deserialize<'de>>(json: &str) -> String {
+    match serde_json::from_str::<T>(json) {
+        Ok(_) => panic!("deserializing {} should have failed", json),
+        Err(error) => error.to_string(),
+    }
+}
+
+macro_rules! serde_test {
+    ($test_name:ident, $struct_name:ident, $primitive_type:ident, $zero_json:expr) => (
+        #[test]
+        fn $test_name() {
+            let max = $primitive_type::MAX;
+            let min = $primitive_type::MIN;
+            let values = [min, min + 1, 0, 1, 2, 3, 7, 64, 100, max / 3, max / 2, max - 1, max];
+
+            for &divisor in &values {
+                if divisor == 0 {
+                    continue;
+                }
+                let reduced_divisor = $struct_name::new(divisor);
+
+                let json = serde_json::to_string(&reduced_divisor).unwrap();
+                assert_eq!(divisor.to_string(), json);
+                let deserialized: $struct_name = serde_json::from_str(&json).unwrap();
+
+                let multiplied_json = serde_json::to_string(&Multiplied(reduced_divisor)).unwrap();
+                let multiplied: Multiplied<$struct_name> = serde_json::from_str(&multiplied_json).unwrap();
+
+                for &numerator in &values {
+                    if numerator.checked_div(divisor).is_none() {
+                        continue;
+                    }
+                    let expected = (numerator / divisor, numerator % divisor);
+                    assert_eq!(expected, $struct_name::div_rem(numerator, deserialized), "Deserialized divisor failed with numerator: {}, divisor: {}", numerator, divisor);
+                    assert_eq!(expected, $struct_name::div_rem(numerator, multiplied.0), "Deserialized multiplier failed with numerator: {}, divisor: {}, json: {}", numerator, divisor, multiplied_json);
+                }
+            }
+
+            assert!(error_message::<$struct_name>("0").contains("from zero"));
+            assert!(error_message::<Multiplied<$struct_name>>($zero_json).contains("from zero"));
+        }
+    )
+}
+
+serde_test!(test_serde_u8, StrengthReducedU8, u8, "[0,0]");
+serde_test!(test_serde_u16, StrengthReducedU16, u16, "[0,0]");
+serde_test!(test_serde_u32, StrengthReducedU32, u32, "[0,0]");
+serde_test!(test_serde_u64, StrengthReducedU64, u64, "[0,0]");
+serde_test!(test_serde_u128, StrengthReducedU128, u128, "[0,[0,0]]");
+serde_test!(test_serde_usize, StrengthReducedUsize, usize, "[0,0]");
+serde_test!(test_serde_i8, StrengthReducedI8, i8, "[0,0]");
+serde_test!(test_serde_i16, StrengthReducedI16, i16, "[0,0]");
+serde_test!(test_serde_i32, StrengthReducedI32, i32, "[0,0]");
+serde_test!(test_serde_i64, StrengthReducedI64, i64, "[0,0]");
+serde_test!(test_serde_i128, StrengthReducedI128, i128, "[0,[0,0]]");
+serde_test!(test_serde_isize, StrengthReducedIsize, isize, "[0,0]");
+serde_test!(test_serde_branchfree_u32, StrengthReducedBranchfreeU32, u32, "[0,0]");
+serde_test!(test_serde_branchfree_u64, StrengthReducedBranchfreeU64, u64, "[0,0]");
+
+#[test]
+fn test_serde_inconsistent_multiplier() {
+    let inconsistent = "the multiplier of a strength-reduced divisor doesn't match its divisor";
+
+    let (divisor, multiplier): (u64, u128) = serde_json::from_str(&serde_json::to_string(&Multiplied(StrengthReducedU64::new(7))).unwrap()).unwrap();
+    for &wrong_multiplier in &[0, 1, multiplier - 1, multiplier + 1, u128::MAX] {
+        let json = serde_json::to_string(&(divisor, wrong_multiplier)).unwrap();
+        assert!(error_message::<Multiplied<StrengthReducedU64>>(&json).contains(inconsistent));
+        assert!(error_message::<Multiplied<StrengthReducedI64>>(&json).contains(inconsistent));
+    }
+
+    // powers of two don't use a multiplier at all
+    assert!(error_message::<Multiplied<StrengthReducedU32>>("[8,1]").contains(inconsistent));
+    assert!(error_message::<Multiplied<StrengthReducedI8>>("[-8,1]").contains(inconsistent));
+
+    let (divisor, (multiplier_hi, multiplier_lo)): (u128, (u128, u128)) = serde_json::from_str(&serde_json::to_string(&Multiplied(StrengthReducedU128::new(u128::MAX / 3))).unwrap()).unwrap();
+    for &wrong_multiplier in &[(0, 0), (multiplier_hi, multiplier_lo - 1), (multiplier_hi, multiplier_lo + 1), (multiplier_hi + 1, multiplier_lo), (u128::MAX, u128::MAX)] {
+        let json = serde_json::to_string(&(divisor, wrong_multiplier)).unwrap();
+        assert!(error_message::<Multiplied<StrengthReducedU128>>(&json).contains(inconsistent));
+    }
+
+    let (divisor, multiplier): (u32, u64) = serde_json::from_str(&serde_json::to_string(&Multiplied(StrengthReducedBranchfreeU32::new(7))).unwrap()).unwrap();
+    for &wrong_multiplier in &[0, multiplier - 1, multiplier + 1, u64::MAX] {
+        let json = serde_json::to_string(&(divisor, wrong_multiplier)).unwrap();
+        assert!(error_message::<Multiplied<StrengthReducedBranchfreeU32>>(&json).contains(inconsistent));
+    }
+
+    let (divisor, multiplier): (u64, u64) = serde_json::from_str(&serde_json::to_string(&Multiplied(StrengthReducedBranchfreeU64::new(u64::MAX / 3))).unwrap()).unwrap();
+    for &wrong_multiplier in &[0, 1, multiplier - 1, multiplier + 1, u64::MAX] {
+        let json = serde_json::to_string(&(divisor, wrong_multiplier)).unwrap();
+        assert!(error_message::<Multiplied<StrengthReducedBranchfreeU64>>(&json).contains(inconsistent));
+    }
+    assert!(error_message::<Multiplied<StrengthReducedBranchfreeU64>>("[8,1]").contains(inconsistent));
+}